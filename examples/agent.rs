@@ -13,6 +13,7 @@ use hyperliquid::{
         exchange::request::{Limit, OrderRequest, OrderType, Tif},
         Chain,
     },
+    utils::{parse_price, parse_size},
     Exchange, Hyperliquid,
 };
 
@@ -49,8 +50,8 @@ async fn main() {
         asset: 4,
         is_buy: true,
         reduce_only: false,
-        limit_px: "1700".to_string(),
-        sz: "0.1".to_string(),
+        limit_px: parse_price(1700.0),
+        sz: parse_size(0.1, 4),
         order_type,
         cloid: None,
     };
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use ethers::{
+    signers::{LocalWallet, Signer},
+    types::Address,
+};
+use hyperliquid::{
+    types::{
+        exchange::request::{Builder, Limit, OrderRequest, OrderType, Tif},
+        Chain,
+    },
+    utils::{parse_price, parse_size},
+    Exchange, Hyperliquid, Info,
+};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let mut exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let info: Info = Hyperliquid::new(Chain::ArbitrumTestnet);
+
+    let builder: Address = "0x0D1d9635D0640821d15e323ac8AdADfA9c111414"
+        .parse()
+        .expect("Invalid address");
+
+    println!("Approving builder {builder:?} to charge up to 1 basis point...");
+    let response = exchange
+        .approve_builder_fee(wallet.clone(), builder, "0.01%".to_string())
+        .await
+        .expect("Failed to approve builder fee");
+
+    println!("Response: {:?}", response);
+
+    let max_fee = info
+        .max_builder_fee(wallet.address(), builder)
+        .await
+        .expect("Failed to fetch max builder fee");
+
+    println!("Max builder fee: {max_fee} tenths of a basis point");
+
+    let order = OrderRequest {
+        asset: 4,
+        is_buy: true,
+        reduce_only: false,
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid: None,
+    };
+
+    exchange.set_builder(Some(Builder {
+        builder,
+        fee: max_fee,
+    }));
+
+    println!("Placing order through the builder...");
+    let response = exchange
+        .place_order(wallet.clone(), vec![order], None)
+        .await
+        .expect("Failed to place order");
+
+    println!("Response: {:?}", response);
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use ethers::signers::LocalWallet;
+use hyperliquid::{
+    types::{exchange::request::CancelFilter, Chain},
+    AssetRegistry, Exchange, Hyperliquid, Info,
+};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::Dev);
+    let info: Info = Hyperliquid::new(Chain::Dev);
+
+    let assets = AssetRegistry::load(&info)
+        .await
+        .expect("Failed to load assets");
+
+    let vault_address = None;
+
+    let filter = CancelFilter {
+        coin: Some("ETH".to_string()),
+        ..Default::default()
+    };
+
+    println!("Cancelling all ETH orders...");
+    let results = exchange
+        .cancel_all(wallet.clone(), &info, &assets, filter, vault_address)
+        .await
+        .expect("Failed to cancel orders");
+
+    for result in results {
+        match result.error {
+            Some(error) => println!("Failed to cancel {}: {error}", result.oid),
+            None => println!("Cancelled {}", result.oid),
+        }
+    }
+}
@@ -9,6 +9,7 @@ use hyperliquid::{
         },
         Chain,
     },
+    utils::{parse_price, parse_size},
     Exchange, Hyperliquid,
 };
 use uuid::Uuid;
@@ -30,8 +31,8 @@ async fn main() {
         asset: 4,
         is_buy: true,
         reduce_only: false,
-        limit_px: "1800".to_string(),
-        sz: "0.1".to_string(),
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
         order_type,
         cloid: None,
     };
@@ -39,31 +40,14 @@ async fn main() {
     let vault_address = None;
 
     println!("Placing order...");
-    let response = exchange
-        .place_order(wallet.clone(), vec![order], vault_address)
+    let results = exchange
+        .place_orders(wallet.clone(), vec![order], vault_address)
         .await
         .expect("Failed to place order");
 
-    let response = match response {
-        Response::Ok(order) => order,
-        Response::Err(error) => panic!("Failed to place order: {:?}", error),
-    };
-
-    let status_type = &response.data.unwrap();
-
-    let status = match status_type {
-        StatusType::Statuses(statuses) => &statuses[0],
-        _ => {
-            panic!("Failed to place order: {:?}", status_type);
-        }
-    };
-
-    let oid = match status {
-        Status::Filled(order) => order.oid,
-        Status::Resting(order) => order.oid,
-        _ => {
-            panic!("Order is not filled or resting, status: {:?}", status);
-        }
+    let oid = match results[0].oid() {
+        Some(oid) => oid,
+        None => panic!("Order is not filled or resting: {:?}", results[0].status),
     };
 
     println!("Order placed: {:?}", oid);
@@ -94,8 +78,8 @@ async fn main() {
         asset: 4,
         is_buy: true,
         reduce_only: false,
-        limit_px: "1700".to_string(),
-        sz: "0.1".to_string(),
+        limit_px: parse_price(1700.0),
+        sz: parse_size(0.1, 4),
         order_type,
         cloid: Some(cloid),
     };
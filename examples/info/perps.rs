@@ -55,6 +55,8 @@ pub async fn main() {
     candle_snapshot(&info).await;
     order_status(&info, &exchange, wallet).await;
     sub_accounts(&info, user).await;
+    user_twap_slice_fills(&info, user).await;
+    twap_history(&info, user).await;
 }
 
 async fn metadata(info: &Info) {
@@ -202,3 +204,13 @@ async fn sub_accounts(info: &Info, user: Address) {
     let sub_accounts = info.sub_accounts(user).await.unwrap();
     println!("Sub accounts for {user} \n{:?}{SEP}", sub_accounts);
 }
+
+async fn user_twap_slice_fills(info: &Info, user: Address) {
+    let fills = info.user_twap_slice_fills(user).await.unwrap();
+    println!("TWAP slice fills for {user} \n{:?}{SEP}", fills);
+}
+
+async fn twap_history(info: &Info, user: Address) {
+    let history = info.twap_history(user).await.unwrap();
+    println!("TWAP history for {user} \n{:?}{SEP}", history);
+}
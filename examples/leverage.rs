@@ -17,11 +17,12 @@ async fn main() {
     let leverage = 2;
     let asset = 4;
     let is_cross = false;
+    let vault_address = None;
 
     println!("Updating leverage to {}x ...", leverage);
 
     let res = exchange
-        .update_leverage(wallet.clone(), leverage, asset, is_cross)
+        .update_leverage(wallet.clone(), leverage, asset, is_cross, vault_address)
         .await
         .unwrap();
 
@@ -32,7 +33,7 @@ async fn main() {
     println!("--\nUpdating isolated margin for ETH to {margin}% ...");
 
     let res = exchange
-        .update_isolated_margin(wallet.clone(), asset, true, margin)
+        .update_isolated_margin(wallet.clone(), asset, true, margin, vault_address)
         .await
         .unwrap();
 
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use ethers::signers::LocalWallet;
+use hyperliquid::{types::Chain, AssetRegistry, Exchange, Hyperliquid, Info};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::Dev);
+    let info: Info = Hyperliquid::new(Chain::Dev);
+
+    let assets = AssetRegistry::load(&info)
+        .await
+        .expect("Failed to load assets");
+
+    let coin = "ETH";
+    let slippage = 0.05;
+    let vault_address = None;
+
+    println!("Market buying 0.01 {coin}...");
+
+    let response = exchange
+        .market_open(
+            wallet.clone(),
+            &info,
+            &assets,
+            coin,
+            true,
+            0.01,
+            slippage,
+            vault_address,
+        )
+        .await
+        .expect("Failed to open position");
+
+    println!("Response: {:?}", response);
+
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+    println!("-----------------");
+    println!("Closing {coin} position...");
+
+    let response = exchange
+        .market_close(
+            wallet.clone(),
+            &info,
+            &assets,
+            coin,
+            None,
+            slippage,
+            vault_address,
+        )
+        .await
+        .expect("Failed to close position");
+
+    println!("Response: {:?}", response);
+}
@@ -9,6 +9,7 @@ use hyperliquid::{
         },
         Chain,
     },
+    utils::{parse_price, parse_size},
     Exchange, Hyperliquid,
 };
 use uuid::Uuid;
@@ -31,8 +32,8 @@ async fn main() {
         asset: 4,
         is_buy: true,
         reduce_only: false,
-        limit_px: "1800".to_string(),
-        sz: "0.1".to_string(),
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
         order_type,
         cloid: Some(cloid),
     };
@@ -69,7 +70,7 @@ async fn main() {
 
     tokio::time::sleep(std::time::Duration::from_secs(5)).await;
 
-    let limit_px = "1710".to_string();
+    let limit_px = parse_price(1710.0);
     // Modifying the order
     println!("Modifying order {oid} limit price to {limit_px}.");
 
@@ -78,7 +79,7 @@ async fn main() {
         is_buy: true,
         reduce_only: false,
         limit_px,
-        sz: "0.1".to_string(),
+        sz: parse_size(0.1, 4),
         order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
         cloid: Some(cloid),
     };
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use ethers::signers::{LocalWallet, Signer};
+use hyperliquid::{
+    types::{
+        exchange::request::{
+            Action, Grouping, Limit, MultiSigBundle, MultiSigSigners, OrderRequest, OrderType, Tif,
+        },
+        Chain,
+    },
+    utils::{parse_price, parse_size},
+    Exchange, Hyperliquid,
+};
+
+#[tokio::main]
+async fn main() {
+    // Keys were randomly generated for testing and shouldn't be used with any real funds
+    let multi_sig_user: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+    let alice: Arc<LocalWallet> = Arc::new(
+        "7aa1d3d5a5b1a3e4dd3e7a7f6b8c1f4f0e5a0f3f2f4e8d2a1c6b9e0d3f7a2c41"
+            .parse()
+            .unwrap(),
+    );
+    let bob: Arc<LocalWallet> = Arc::new(
+        "3c9d1f7e2b6a4d8c0e5f1a9b7d3c2e6f4a8b0d1c9e7f5a3b2d4c6e8f0a1b3d5c"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+
+    let signers = MultiSigSigners {
+        authorized_users: vec![alice.address(), bob.address()],
+        threshold: 2,
+    };
+
+    println!(
+        "Converting {:?} to a multi-sig user...",
+        multi_sig_user.address()
+    );
+    let response = exchange
+        .convert_to_multi_sig_user(multi_sig_user.clone(), signers.clone())
+        .await
+        .expect("Failed to convert to multi-sig user");
+
+    println!("Response: {:?}", response);
+
+    let order = OrderRequest {
+        asset: 4,
+        is_buy: true,
+        reduce_only: false,
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid: None,
+    };
+
+    // Alice will submit the action on behalf of the multi-sig user
+    let mut bundle = exchange
+        .multi_sig_bundle(
+            multi_sig_user.address(),
+            alice.address(),
+            signers,
+            Action::Order {
+                orders: vec![order],
+                grouping: Grouping::Na,
+                builder: None,
+            },
+            None,
+        )
+        .expect("Failed to create multi-sig bundle");
+
+    exchange
+        .sign_multi_sig(alice.clone(), &mut bundle)
+        .await
+        .expect("Failed to sign bundle");
+
+    // The bundle can be shared as json with the other signers
+    let json = serde_json::to_string(&bundle).expect("Failed to serialize bundle");
+    let mut bundle: MultiSigBundle = serde_json::from_str(&json).expect("Failed to parse bundle");
+
+    exchange
+        .sign_multi_sig(bob.clone(), &mut bundle)
+        .await
+        .expect("Failed to sign bundle");
+
+    println!("Signed by: {:?}", bundle.signed_by());
+
+    println!("Submitting multi-sig order...");
+    let response = exchange
+        .submit_multi_sig(alice.clone(), bundle)
+        .await
+        .expect("Failed to submit multi-sig action");
+
+    println!("Response: {:?}", response);
+}
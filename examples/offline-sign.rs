@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use ethers::signers::LocalWallet;
+use hyperliquid::{
+    types::{
+        exchange::request::{Action, Grouping, Limit, OrderRequest, OrderType, Request, Tif},
+        Chain,
+    },
+    utils::{parse_price, parse_size},
+    Exchange, Hyperliquid,
+};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+
+    let action = Action::Order {
+        orders: vec![OrderRequest {
+            asset: 4,
+            is_buy: true,
+            reduce_only: false,
+            limit_px: parse_price(1800.0),
+            sz: parse_size(0.1, 4),
+            order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+            cloid: None,
+        }],
+        grouping: Grouping::Na,
+        builder: None,
+    };
+
+    // On the offline machine: sign the action without sending it
+    let request = exchange
+        .sign_action(wallet.clone(), action, None)
+        .await
+        .expect("Failed to sign action");
+
+    let signed = serde_json::to_string(&request).expect("Failed to serialize request");
+
+    println!("Signed request: {signed}");
+
+    // On the online machine: submit the signed request
+    let request: Request = serde_json::from_str(&signed).expect("Failed to parse request");
+
+    let response = exchange
+        .submit(&request)
+        .await
+        .expect("Failed to submit request");
+
+    println!("Response: {:?}", response);
+}
@@ -7,7 +7,7 @@ use hyperliquid::{
         Chain,
     },
     utils::{parse_price, parse_size},
-    Exchange, Hyperliquid,
+    AssetRegistry, Exchange, Hyperliquid, Info,
 };
 use uuid::Uuid;
 
@@ -21,9 +21,16 @@ async fn main() {
     );
 
     let exchange: Exchange = Hyperliquid::new(Chain::Dev);
+    let info: Info = Hyperliquid::new(Chain::Dev);
 
-    let asset = 4;
-    let sz_decimals = 4;
+    let assets = AssetRegistry::load(&info)
+        .await
+        .expect("Failed to load assets");
+
+    let eth = assets.resolve("ETH").expect("Failed to resolve ETH");
+
+    let asset = eth.id;
+    let sz_decimals = eth.sz_decimals;
 
     let order_type = OrderType::Limit(Limit { tif: Tif::Gtc });
 
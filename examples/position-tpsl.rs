@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use ethers::signers::LocalWallet;
+use hyperliquid::{
+    types::{
+        exchange::request::{OrderRequest, OrderType, TpSl, Trigger},
+        Chain,
+    },
+    utils::{parse_price, parse_size},
+    AssetRegistry, Exchange, Hyperliquid, Info,
+};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::Dev);
+    let info: Info = Hyperliquid::new(Chain::Dev);
+
+    let assets = AssetRegistry::load(&info)
+        .await
+        .expect("Failed to load assets");
+
+    let coin = "ETH";
+    let slippage = 0.05;
+    let vault_address = None;
+
+    println!("Market buying 0.01 {coin}...");
+
+    let response = exchange
+        .market_open(
+            wallet.clone(),
+            &info,
+            &assets,
+            coin,
+            true,
+            0.01,
+            slippage,
+            vault_address,
+        )
+        .await
+        .expect("Failed to open position");
+
+    println!("Response: {:?}", response);
+
+    let asset = assets.resolve(coin).expect("Failed to resolve asset");
+
+    // a size of 0 follows the size of the whole position
+    let tp = OrderRequest {
+        asset: asset.id,
+        is_buy: false,
+        reduce_only: true,
+        limit_px: parse_price(4000.0),
+        sz: parse_size(0.0, asset.sz_decimals),
+        order_type: OrderType::Trigger(Trigger {
+            is_market: true,
+            trigger_px: parse_price(4000.0),
+            tpsl: TpSl::Tp,
+        }),
+        cloid: None,
+    };
+
+    let sl = OrderRequest {
+        asset: asset.id,
+        is_buy: false,
+        reduce_only: true,
+        limit_px: parse_price(2000.0),
+        sz: parse_size(0.0, asset.sz_decimals),
+        order_type: OrderType::Trigger(Trigger {
+            is_market: true,
+            trigger_px: parse_price(2000.0),
+            tpsl: TpSl::Sl,
+        }),
+        cloid: None,
+    };
+
+    println!("-----------------");
+    println!("Placing position tpsl for {coin}...");
+
+    let response = exchange
+        .position_tpsl(wallet.clone(), &info, &assets, vec![tp, sl], vault_address)
+        .await
+        .expect("Failed to place position tpsl");
+
+    println!("Response: {:?}", response);
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use ethers::signers::{LocalWallet, Signer};
+use hyperliquid::{types::Chain, Exchange, Hyperliquid, Info};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let info: Info = Hyperliquid::new(Chain::ArbitrumTestnet);
+
+    let user = wallet.address();
+
+    let validator = "0x946bf3135c7d15e4462b510f74b6e304aabb5b21"
+        .parse()
+        .expect("Invalid address");
+
+    // 1 HYPE
+    let wei = 100_000_000;
+
+    println!("Staking 1 HYPE...");
+    let response = exchange
+        .staking_deposit(wallet.clone(), wei)
+        .await
+        .expect("Failed to stake");
+
+    println!("Response: {:?}", response);
+
+    println!("Delegating 1 HYPE to {validator:?}...");
+    let response = exchange
+        .token_delegate(wallet.clone(), validator, wei, false)
+        .await
+        .expect("Failed to delegate");
+
+    println!("Response: {:?}", response);
+
+    let summary = info.delegator_summary(user).await.unwrap();
+    println!("Delegator summary: {:?}", summary);
+
+    let delegations = info.delegations(user).await.unwrap();
+    println!("Delegations: {:?}", delegations);
+
+    let history = info.delegator_history(user).await.unwrap();
+    println!("Delegator history: {:?}", history);
+
+    let rewards = info.delegator_rewards(user).await.unwrap();
+    println!("Delegator rewards: {:?}", rewards);
+}
@@ -20,6 +20,8 @@ async fn main() {
 
     let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
 
+    let vault_address = None;
+
     println!("Creating subaccount...");
     let name = {
         let suffix = timestamp().to_string();
@@ -31,7 +33,7 @@ async fn main() {
     };
 
     let response = exchange
-        .create_sub_account(wallet.clone(), name.clone())
+        .create_sub_account(wallet.clone(), name.clone(), vault_address)
         .await
         .expect("Failed to create subaccount");
 
@@ -66,7 +68,7 @@ async fn main() {
     println!("Renaming subaccount to: {}", new_name);
 
     let response = exchange
-        .sub_account_modify(wallet.clone(), new_name, sub_account_user)
+        .sub_account_modify(wallet.clone(), new_name, sub_account_user, vault_address)
         .await
         .expect("Failed to rename subaccount");
 
@@ -88,7 +90,13 @@ async fn main() {
     let is_deposit = true;
 
     let response = exchange
-        .sub_account_transfer(wallet.clone(), is_deposit, sub_account_user, usd)
+        .sub_account_transfer(
+            wallet.clone(),
+            is_deposit,
+            sub_account_user,
+            usd,
+            vault_address,
+        )
         .await
         .expect("Failed to deposit funds");
 
@@ -106,7 +114,13 @@ async fn main() {
     println!("Withdrawing funds from subaccount...");
 
     let response = exchange
-        .sub_account_transfer(wallet.clone(), !is_deposit, sub_account_user, usd)
+        .sub_account_transfer(
+            wallet.clone(),
+            !is_deposit,
+            sub_account_user,
+            usd,
+            vault_address,
+        )
         .await
         .expect("Failed to withdraw funds");
 
@@ -2,7 +2,13 @@ use std::sync::Arc;
 
 use ethers::signers::LocalWallet;
 use hyperliquid::{
-    types::{exchange::request::TwapRequest, Chain},
+    types::{
+        exchange::{
+            request::TwapRequest,
+            response::{Response, Status, StatusType},
+        },
+        Chain,
+    },
     utils::parse_size,
     Exchange, Hyperliquid,
 };
@@ -39,4 +45,20 @@ async fn main() {
         .expect("Failed to place twap order");
 
     println!("Response: {:?}", response);
+
+    let twap_id = match response {
+        Response::Ok(order) => match order.data {
+            Some(StatusType::Status(Status::Running(twap))) => twap.twap_id,
+            _ => panic!("Expected a running twap order"),
+        },
+        Response::Err(error) => panic!("Failed to place twap order: {:?}", error),
+    };
+
+    println!("Cancelling the TWAP order...");
+    let response = exchange
+        .cancel_twap(wallet.clone(), asset, twap_id, vault_address)
+        .await
+        .expect("Failed to cancel twap order");
+
+    println!("Response: {:?}", response);
 }
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use ethers::signers::{LocalWallet, Signer};
+use hyperliquid::{types::Chain, Exchange, Hyperliquid, Info};
+
+#[tokio::main]
+async fn main() {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let info: Info = Hyperliquid::new(Chain::ArbitrumTestnet);
+
+    let user = wallet.address();
+
+    let amount = "1".to_string();
+
+    println!("Moving {amount} USDC from spot to perp for {user:?}");
+
+    let res = exchange
+        .usd_class_transfer(wallet.clone(), amount, true)
+        .await
+        .unwrap();
+
+    println!("Response: {:?}", res);
+
+    let state = info.user_state(user).await.unwrap();
+
+    println!("Withdrawable: {}", state.withdrawable);
+}
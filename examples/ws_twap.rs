@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use ethers::signers::{LocalWallet, Signer};
+use hyperliquid::{
+    types::{
+        websocket::{
+            request::{Channel, Subscription},
+            response::Response,
+        },
+        Chain,
+    },
+    Hyperliquid, Result, Websocket,
+};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    let wallet: Arc<LocalWallet> = Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    );
+
+    let user = wallet.address();
+
+    let mut ws: Websocket = Hyperliquid::new(Chain::Dev);
+
+    ws.connect().await?;
+
+    let twap_states = Channel {
+        id: 2,
+        sub: Subscription::TwapStates { user },
+    };
+
+    let twap_slice_fills = Channel {
+        id: 3,
+        sub: Subscription::UserTwapSliceFills { user },
+    };
+
+    ws.subscribe(&[twap_states, twap_slice_fills]).await?;
+
+    let handler = |event: Response| async move {
+        match event {
+            Response::TwapStates(states) => {
+                println!("Received TWAP States: \n--\n{:?}", states);
+            }
+            Response::UserTwapSliceFills(fills) => {
+                println!("Received TWAP Slice Fills: \n--\n{:?}", fills);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    };
+
+    ws.next(handler).await?;
+
+    ws.disconnect().await?;
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use crate::{
     client::Client,
     config::Config,
     exchange::Exchange,
     info::Info,
+    nonce::NonceManager,
     types::{Chain, API},
     Websocket,
 };
@@ -35,7 +36,7 @@ impl Hyperliquid for Info {
     fn new_with_config(chain: Chain, config: &Config) -> Self {
         Self {
             chain,
-            client: Client::new(config.rest_endpoint.clone()),
+            client: Client::new(config),
         }
     }
 }
@@ -52,7 +53,12 @@ impl Hyperliquid for Exchange {
     fn new_with_config(chain: Chain, config: &Config) -> Self {
         Self {
             chain,
-            client: Client::new(config.rest_endpoint.clone()),
+            client: Client::new(config),
+            nonce_provider: Arc::new(NonceManager::default()),
+            expires_after: None,
+            retry_policy: None,
+            account: None,
+            builder: None,
         }
     }
 }
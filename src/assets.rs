@@ -0,0 +1,138 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use crate::{
+    error::{Error, Result},
+    info::Info,
+    types::info::response::{SpotMeta, Universe},
+};
+
+/// Offset added to the spot universe index to get the asset id of a spot pair
+pub const SPOT_ASSET_OFFSET: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    Perp,
+    Spot,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetInfo {
+    /// Asset id to use in order, cancel and leverage requests
+    pub id: u32,
+    /// Coin name as used by the info endpoint, e.g. `ETH`, `PURR/USDC` or `@107`
+    pub name: String,
+    pub sz_decimals: u32,
+    pub kind: AssetKind,
+}
+
+/// Resolves coin names to asset ids and size decimals.
+///
+/// Perp assets are looked up by name (e.g. `ETH`), spot pairs by pair name (e.g. `PURR/USDC`)
+/// or by their `@{index}` alias (e.g. `@107`). Spot asset ids are `10000 + index`.
+#[derive(Debug, Default)]
+pub struct AssetRegistry {
+    assets: RwLock<HashMap<String, AssetInfo>>,
+}
+
+impl AssetRegistry {
+    /// Build a registry from already fetched metadata
+    pub fn new(meta: &Universe, spot_meta: &SpotMeta) -> Self {
+        Self {
+            assets: RwLock::new(Self::index(meta, spot_meta)),
+        }
+    }
+
+    /// Fetch perp and spot metadata and build a registry from it
+    pub async fn load(info: &Info) -> Result<Self> {
+        let registry = Self::default();
+
+        registry.refresh(info).await?;
+
+        Ok(registry)
+    }
+
+    /// Re-fetch perp and spot metadata, e.g. after a new asset has been listed
+    pub async fn refresh(&self, info: &Info) -> Result<()> {
+        let meta = info.metadata().await?;
+        let spot_meta = info.spot_meta().await?;
+
+        self.update(&meta, &spot_meta);
+
+        Ok(())
+    }
+
+    /// Replace the registry contents with the given metadata
+    pub fn update(&self, meta: &Universe, spot_meta: &SpotMeta) {
+        let assets = Self::index(meta, spot_meta);
+
+        *self.assets.write().unwrap_or_else(|e| e.into_inner()) = assets;
+    }
+
+    /// Resolve a coin name to its asset info
+    ///
+    /// # Arguments
+    /// * `coin` - The coin to resolve e.g `ETH`, `PURR/USDC` or `@107`
+    pub fn resolve(&self, coin: &str) -> Result<AssetInfo> {
+        let assets = self.assets.read().unwrap_or_else(|e| e.into_inner());
+
+        assets
+            .get(coin)
+            .cloned()
+            .ok_or_else(|| Error::AssetNotFound(coin.to_string()))
+    }
+
+    /// Resolve a coin name to its asset id
+    pub fn asset(&self, coin: &str) -> Result<u32> {
+        Ok(self.resolve(coin)?.id)
+    }
+
+    /// Resolve a coin name to its size decimals
+    pub fn sz_decimals(&self, coin: &str) -> Result<u32> {
+        Ok(self.resolve(coin)?.sz_decimals)
+    }
+
+    /// Find the asset with the given asset id
+    pub fn by_id(&self, id: u32) -> Option<AssetInfo> {
+        let assets = self.assets.read().unwrap_or_else(|e| e.into_inner());
+
+        assets.values().find(|asset| asset.id == id).cloned()
+    }
+
+    fn index(meta: &Universe, spot_meta: &SpotMeta) -> HashMap<String, AssetInfo> {
+        let mut assets = HashMap::new();
+
+        for (index, asset) in meta.universe.iter().enumerate() {
+            assets.insert(
+                asset.name.clone(),
+                AssetInfo {
+                    id: index as u32,
+                    name: asset.name.clone(),
+                    sz_decimals: asset.sz_decimals as u32,
+                    kind: AssetKind::Perp,
+                },
+            );
+        }
+
+        for pair in &spot_meta.universe {
+            let Some(base) = pair
+                .tokens
+                .first()
+                .and_then(|token| spot_meta.tokens.iter().find(|t| t.index == *token))
+            else {
+                continue;
+            };
+
+            let info = AssetInfo {
+                id: SPOT_ASSET_OFFSET + pair.index as u32,
+                name: pair.name.clone(),
+                sz_decimals: base.sz_decimals as u32,
+                kind: AssetKind::Spot,
+            };
+
+            assets.insert(format!("@{}", pair.index), info.clone());
+            assets.insert(pair.name.clone(), info);
+        }
+
+        assets
+    }
+}
@@ -1,53 +1,78 @@
-use reqwest::{
-    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
-    Response,
-};
+use std::sync::Arc;
+
 use serde::{de::DeserializeOwned, ser::Serialize};
 
-use crate::{error::Result, types::API};
+use crate::{
+    config::Config,
+    error::{Error, Result},
+    rate_limit::{RateLimiter, Weighted},
+    transport::{FailedTransport, ReqwestTransport, Transport},
+    types::{exchange::response::Response as ExchangeResponse, API},
+};
 
+#[derive(Clone)]
 pub struct Client {
-    inner_client: reqwest::Client,
-    host: String,
+    transport: Arc<dyn Transport>,
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl Client {
-    pub fn new(host: String) -> Self {
+    /// If no transport is set on `config` and the http client can't be built from it, every
+    /// request fails with [`Error::HttpClient`], see [`ReqwestTransport::new`]
+    pub fn new(config: &Config) -> Self {
+        let transport =
+            config
+                .transport
+                .clone()
+                .unwrap_or_else(|| match ReqwestTransport::new(config) {
+                    Ok(transport) => Arc::new(transport),
+                    Err(e) => Arc::new(FailedTransport(e.to_string())),
+                });
+
         Self {
-            inner_client: reqwest::Client::new(),
-            host,
+            transport,
+            rate_limiter: config.rate_limiter.clone(),
         }
     }
+
+    /// Limiter the requests of this client are throttled with, shared with its clones
+    pub fn rate_limiter(&self) -> Option<&Arc<RateLimiter>> {
+        self.rate_limiter.as_ref()
+    }
 }
 
 impl Client {
     pub async fn post<T: DeserializeOwned>(
         &self,
         endpoint: &API,
-        req: &impl Serialize,
+        req: &(impl Serialize + Weighted),
     ) -> Result<T> {
-        let url = &format!("{}{}", self.host, String::from(endpoint));
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire(req.weight()).await?;
+        }
+
+        let payload = serde_json::to_value(req)?;
 
-        let response = self
-            .inner_client
-            .post(url)
-            .headers(self.build_headers())
-            .json(req)
-            .send()
-            .await?;
+        let response = self.transport.post(endpoint, &payload).await?;
 
-        self.handler(response).await
+        self.handler(response.status, response.body)
     }
 }
 
 impl Client {
-    fn build_headers(&self) -> HeaderMap {
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        headers
-    }
+    /// Check the status code and decode the body, keeping the raw body in errors
+    ///
+    /// Rejected actions, e.g. `{"status":"err","response":"Invalid nonce"}`, are errors too,
+    /// with a variant picked by [`Error::from_response`]
+    fn handler<T: DeserializeOwned>(&self, status: u16, body: String) -> Result<T> {
+        if !(200..300).contains(&status) {
+            return Err(Error::from_response(status, body));
+        }
+
+        if let Ok(ExchangeResponse::Err(message)) = serde_json::from_str(&body) {
+            return Err(Error::from_response(status, message));
+        }
 
-    async fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        response.json::<T>().await.map_err(Into::into)
+        serde_json::from_str(&body).map_err(|e| Error::Decode(e, body))
     }
 }
@@ -1,6 +1,37 @@
+use std::{sync::Arc, time::Duration};
+
+use reqwest::{header::HeaderMap, Proxy};
+
+use crate::{error::Result, rate_limit::RateLimiter, transport::Transport};
+
+/// User agent sent with every request, unless overridden with [`Config::headers`]
+pub const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "-rs/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Clone)]
 pub struct Config {
     pub rest_endpoint: String,
     pub ws_endpoint: String,
+    /// Limiter shared by every `Info` and `Exchange` built from this config, requests are
+    /// not throttled if `None`
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// Timeout of a whole request, from connecting until the response body is read
+    pub request_timeout: Option<Duration>,
+    /// Timeout of the connect phase of a request
+    pub connect_timeout: Option<Duration>,
+    /// Proxy all requests are sent through
+    pub proxy: Option<Proxy>,
+    /// Headers sent with every request, e.g. an API key expected by a gateway
+    pub headers: HeaderMap,
+    /// Maximum number of idle connections kept per host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long idle connections are kept open
+    pub pool_idle_timeout: Option<Duration>,
+    /// Pre-built client to send requests with instead of building one from this config.
+    /// The timeouts, proxy and pool options are then ignored, extra headers are still sent.
+    pub http_client: Option<reqwest::Client>,
+    /// Transport to send requests with instead of HTTP, e.g. a
+    /// [`MemoryTransport`](crate::MemoryTransport) in tests. All HTTP options are then ignored.
+    pub transport: Option<Arc<dyn Transport>>,
 }
 
 impl Default for Config {
@@ -11,23 +42,36 @@ impl Default for Config {
 
 impl Config {
     pub fn mainnet() -> Self {
-        Self {
-            rest_endpoint: "https://api.hyperliquid.xyz".to_string(),
-            ws_endpoint: "wss://api.hyperliquid.xyz/ws".to_string(),
-        }
+        Self::with_endpoints(
+            "https://api.hyperliquid.xyz",
+            "wss://api.hyperliquid.xyz/ws",
+        )
     }
 
     pub fn testnet() -> Self {
-        Self {
-            rest_endpoint: "https://api.hyperliquid-testnet.xyz".to_string(),
-            ws_endpoint: "wss://api.hyperliquid-testnet.xyz/ws".to_string(),
-        }
+        Self::with_endpoints(
+            "https://api.hyperliquid-testnet.xyz",
+            "wss://api.hyperliquid-testnet.xyz/ws",
+        )
     }
 
     pub fn local() -> Self {
+        Self::with_endpoints("http://localhost:3001", "ws://localhost:3001/ws")
+    }
+
+    fn with_endpoints(rest_endpoint: &str, ws_endpoint: &str) -> Self {
         Self {
-            rest_endpoint: "http://localhost:3001".to_string(),
-            ws_endpoint: "ws://localhost:3001/ws".to_string(),
+            rest_endpoint: rest_endpoint.to_string(),
+            ws_endpoint: ws_endpoint.to_string(),
+            rate_limiter: None,
+            request_timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            headers: HeaderMap::new(),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http_client: None,
+            transport: None,
         }
     }
 
@@ -38,4 +82,77 @@ impl Config {
     pub fn set_ws_endpoint(&mut self, endpoint: String) {
         self.ws_endpoint = endpoint;
     }
+
+    pub fn set_rate_limiter(&mut self, rate_limiter: Arc<RateLimiter>) {
+        self.rate_limiter = Some(rate_limiter);
+    }
+
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = Some(timeout);
+    }
+
+    pub fn set_connect_timeout(&mut self, timeout: Duration) {
+        self.connect_timeout = Some(timeout);
+    }
+
+    pub fn set_proxy(&mut self, proxy: Proxy) {
+        self.proxy = Some(proxy);
+    }
+
+    pub fn set_headers(&mut self, headers: HeaderMap) {
+        self.headers = headers;
+    }
+
+    pub fn set_pool_max_idle_per_host(&mut self, max: usize) {
+        self.pool_max_idle_per_host = Some(max);
+    }
+
+    pub fn set_pool_idle_timeout(&mut self, timeout: Duration) {
+        self.pool_idle_timeout = Some(timeout);
+    }
+
+    /// Send requests with `http_client`, e.g. to share its connection pool
+    pub fn set_http_client(&mut self, http_client: reqwest::Client) {
+        self.http_client = Some(http_client);
+    }
+
+    /// Send requests with `transport` instead of HTTP
+    pub fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = Some(transport);
+    }
+
+    /// The client requests are sent with: the injected client if any, otherwise a new one
+    /// built from the timeouts, proxy and pool options.
+    ///
+    /// `Info` and `Exchange` built from the same config each get their own connection pool,
+    /// unless a client is injected, e.g. `config.set_http_client(config.http_client()?)`.
+    pub fn http_client(&self) -> Result<reqwest::Client> {
+        if let Some(http_client) = &self.http_client {
+            return Ok(http_client.clone());
+        }
+
+        let mut builder = reqwest::Client::builder().user_agent(USER_AGENT);
+
+        if let Some(timeout) = self.request_timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        Ok(builder.build()?)
+    }
 }
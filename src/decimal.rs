@@ -0,0 +1,39 @@
+use rust_decimal::Decimal;
+
+use crate::{error::Result, types::Number, Error};
+
+/// Exact value of a [`Number`], e.g. of a price or a balance
+///
+/// # Examples
+/// ```
+/// use hyperliquid::ToDecimal;
+///
+/// let pnl = "-1.234567".to_decimal().unwrap() - "0.1234".to_decimal().unwrap();
+///
+/// assert_eq!(pnl.to_string(), "-1.357967");
+/// assert!("not a number".to_decimal().is_err());
+/// ```
+pub trait ToDecimal {
+    /// Fails with [`Error::InvalidNumber`] if the value isn't a number or is out of range
+    fn to_decimal(&self) -> Result<Decimal>;
+}
+
+impl ToDecimal for str {
+    fn to_decimal(&self) -> Result<Decimal> {
+        self.parse()
+            .map_err(|e| Error::InvalidNumber(format!("{self}: {e}")))
+    }
+}
+
+/// Wire format of `value`, without the trailing zeros the exchange drops when hashing an action
+///
+/// # Examples
+/// ```
+/// use hyperliquid::from_decimal;
+///
+/// assert_eq!(from_decimal("1800.00".parse().unwrap()), "1800");
+/// assert_eq!(from_decimal("0.100".parse().unwrap()), "0.1");
+/// ```
+pub fn from_decimal(value: Decimal) -> Number {
+    value.normalize().to_string()
+}
@@ -1,5 +1,5 @@
-use ethers::signers::WalletError;
-use std::time::SystemTimeError;
+use ethers::{signers::WalletError, types::Address};
+use std::{num::ParseFloatError, time::SystemTimeError};
 use thiserror::Error as ThisError;
 use tokio_tungstenite::tungstenite;
 use tungstenite::Error as WsError;
@@ -16,6 +16,12 @@ pub enum Error {
     TimestampError(SystemTimeError),
     #[error("Wallet error: {0:?}")]
     WalletError(WalletError),
+    #[error("Signer error: {0}")]
+    SignerError(String),
+    #[error("Signature error: {0}")]
+    SignatureError(String),
+    #[error("Unauthorized signer: {0:?}")]
+    UnauthorizedSigner(Address),
     #[error("WS error: {0:?}")]
     WsError(WsError),
     #[error("Not connected")]
@@ -30,8 +36,109 @@ pub enum Error {
     MissingSubscriptionResponse(Subscription),
     #[error("Rmp serde error: {0:?}")]
     RmpSerdeError(String),
+    #[error("Request weight {weight} exceeds remaining rate limit budget {remaining}")]
+    RateLimitExceeded { weight: u32, remaining: u32 },
+    #[error("Request weight {weight} exceeds rate limit capacity {capacity}")]
+    WeightExceedsCapacity { weight: u32, capacity: u32 },
+    #[error("Address {address:?} used {used} of its {cap} actions")]
+    AddressRateLimitExceeded {
+        address: Address,
+        used: u64,
+        cap: u64,
+    },
     #[error("Chain {0} not supported")]
     ChainNotSupported(String),
+    #[error("Signed for {0}, but submitted on {1}")]
+    ChainMismatch(String, String),
+    #[error("Asset {0} not found")]
+    AssetNotFound(String),
+    #[error("No mid price for {0}")]
+    MidNotFound(String),
+    #[error("No open position for {0}")]
+    NoPosition(String),
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+    #[error("Multi-sig threshold not met: {0} of {1} signatures")]
+    MultiSigThreshold(usize, usize),
+    #[error("Invalid multi-sig signers: {0}")]
+    InvalidSigners(String),
+    #[error("Orders were placed by a submission reported as failed, oids {0:?}")]
+    SubmissionLanded(Vec<u64>),
+    #[error("Submission failed with {0}, and whether its orders were placed is unknown: {1}")]
+    SubmissionUnconfirmed(Box<Error>, Box<Error>),
+    #[error("Failed to build http client: {0}")]
+    HttpClient(String),
+    #[error("HTTP error {0}: {1}")]
+    Http(u16, String),
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+    #[error("Invalid nonce: {0}")]
+    InvalidNonce(String),
+    #[error("Unknown user: {0}")]
+    UnknownUser(String),
+    #[error("Failed to decode response {1}: {0:?}")]
+    Decode(serde_json::Error, String),
+    #[error("Exchange error: {0}")]
+    ExchangeError(String),
+    #[error("Unexpected response: {0}")]
+    UnexpectedResponse(String),
+    #[error("Invalid number: {0}")]
+    InvalidNumber(String),
+    #[error("Parse float error: {0:?}")]
+    ParseFloat(ParseFloatError),
+}
+
+impl Error {
+    /// Error returned by the API for a rejected request, mapping known failures to their own variant
+    ///
+    /// # Arguments
+    /// * `status` - The HTTP status code of the response
+    /// * `message` - The raw body, or the message of an `err` response
+    pub fn from_response(status: u16, message: String) -> Self {
+        let lowercase = message.to_lowercase();
+
+        if status == 429 || lowercase.contains("too many") || lowercase.contains("rate limit") {
+            Self::RateLimited(message)
+        } else if lowercase.contains("nonce") {
+            Self::InvalidNonce(message)
+        } else if lowercase.contains("signature")
+            || (lowercase.contains("api wallet") && lowercase.contains("does not exist"))
+        {
+            // a bad signature recovers to an address unknown to the exchange
+            Self::InvalidSignature(message)
+        } else if lowercase.contains("must deposit before performing actions")
+            || lowercase.contains("user does not exist")
+            || lowercase.contains("unknown user")
+        {
+            Self::UnknownUser(message)
+        } else if (200..300).contains(&status) {
+            Self::ExchangeError(message)
+        } else {
+            Self::Http(status, message)
+        }
+    }
+
+    /// Whether the request failed because of the network or the server, and may succeed if sent again
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Reqwest(e) => {
+                e.is_connect()
+                    || e.is_timeout()
+                    || e.status().is_some_and(|status| status.is_server_error())
+            }
+            Self::Http(status, _) => *status >= 500,
+            Self::RateLimited(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the request was turned down without being executed, e.g. it never reached the
+    /// server or was rate limited
+    pub fn is_not_executed(&self) -> bool {
+        matches!(self, Self::Reqwest(e) if e.is_connect()) || matches!(self, Self::RateLimited(_))
+    }
 }
 
 impl From<reqwest::Error> for Error {
@@ -63,3 +170,9 @@ impl From<serde_json::Error> for Error {
         Self::Json(e)
     }
 }
+
+impl From<ParseFloatError> for Error {
+    fn from(e: ParseFloatError) -> Self {
+        Self::ParseFloat(e)
+    }
+}
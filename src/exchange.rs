@@ -1,32 +1,58 @@
-use std::{sync::Arc, time::SystemTime};
+use std::{sync::Arc, time::Duration};
 
 use ethers::{
-    signers::{LocalWallet, Signer},
-    types::{Address, Signature, H256},
+    signers::Signer,
+    types::{transaction::eip712::Eip712, Address, Signature, H256},
     utils::to_checksum,
 };
 
 use crate::{
+    assets::{AssetInfo, AssetRegistry},
     client::Client,
     error::Result,
+    info::Info,
+    nonce::NonceProvider,
+    rate_limit::Weighted,
+    retry::{resubmission, Resubmission, RetryPolicy},
     types::{
         agent::l1,
         exchange::{
             request::{
-                Action, ApproveAgent, CancelByCloidRequest, CancelRequest, Grouping, ModifyRequest,
-                OrderRequest, Request, TwapRequest, UsdSend, Withdraw3,
+                Action, ApproveAgent, ApproveBuilderFee, Builder, CDeposit, CWithdraw,
+                CancelByCloidRequest, CancelFilter, CancelRequest, ConvertToMultiSigUser, Grouping,
+                Limit, ModifyRequest, MultiSigBundle, MultiSigSigners, OrderRequest, OrderType,
+                Request, SendMultiSig, SpotSend, Tif, TokenDelegate, TwapRequest, UsdClassTransfer,
+                UsdSend, Withdraw3,
             },
-            response::Response,
+            response::{CancelResult, OrderResult, Response},
         },
-        Chain, HyperliquidChain, API,
+        info::{request::Request as InfoRequest, response::OrderStatus},
+        Chain, Cloid, HyperliquidChain, Number, Oid, API,
     },
+    utils::{parse_size, round_price, RoundingMode},
     Error,
 };
 
+/// Maximum number of orders cancelled in a single action by [`Exchange::cancel_all`]
+const CANCEL_BATCH_SIZE: usize = 100;
+
 /// Endpoint to interact with and trade on the Hyperliquid chain.
+///
+/// Actions can be signed by any [`Signer`], e.g. a `LocalWallet`, a keystore-backed wallet,
+/// a remote signer or a test double.
 pub struct Exchange {
     pub client: Client,
     pub chain: Chain,
+    pub nonce_provider: Arc<dyn NonceProvider>,
+    /// How long after their nonce L1 actions signed by this exchange are rejected,
+    /// see [`Exchange::set_expires_after`]
+    pub expires_after: Option<Duration>,
+    /// How failed submissions are retried, never if `None`, see [`Exchange::set_retry_policy`]
+    pub retry_policy: Option<RetryPolicy>,
+    /// Account requests without a vault address are submitted for, see [`Exchange::set_account`]
+    pub account: Option<Address>,
+    /// Builder orders and modifies are routed through, see [`Exchange::set_builder`]
+    pub builder: Option<Builder>,
 }
 
 impl Exchange {
@@ -36,35 +62,137 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `orders` - The orders to place
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    ///  e.g. `0x0000000000000000000000000000000000000000`
+    ///   e.g. `0x0000000000000000000000000000000000000000`
     ///
     ///  # Note
     /// * `cloid` in argument `order` is an optional 128 bit hex string, e.g. `0x1234567890abcdef1234567890abcdef`
-    pub async fn place_order(
+    pub async fn place_order<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         orders: Vec<OrderRequest>,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::Order {
             grouping: Grouping::Na,
             orders,
+            builder: self.builder.clone(),
         };
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+        self.submit(&request).await
+    }
 
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
+    /// Place orders and map each order to its result
+    ///
+    /// Same as [`Exchange::place_order`], but top-level rejections are returned as
+    /// [`Error::ExchangeError`] and each order gets a typed [`OrderResult`], in the same order
+    /// as `orders`
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the order with
+    /// * `orders` - The orders to place
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn place_orders<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        orders: Vec<OrderRequest>,
+        vault_address: Option<Address>,
+    ) -> Result<Vec<OrderResult>> {
+        let response = self
+            .place_order(wallet, orders.clone(), vault_address)
+            .await?;
+
+        response.into_order_results(&orders)
+    }
+
+    /// Open a position at market price, implemented as an IOC limit order bounded by `slippage`
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the order with
+    /// * `info` - Used to fetch the current mid price
+    /// * `assets` - Used to resolve `coin` to its asset id and size decimals
+    /// * `coin` - The coin to trade e.g `ETH`, `PURR/USDC` or `@107`
+    /// * `is_buy` - true if buying, false if selling
+    /// * `sz` - The size of the order
+    /// * `slippage` - The max slippage from the mid price, e.g. `0.05` for 5%
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn market_open<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        info: &Info,
+        assets: &AssetRegistry,
+        coin: &str,
+        is_buy: bool,
+        sz: f64,
+        slippage: f64,
+        vault_address: Option<Address>,
+    ) -> Result<Response> {
+        let asset = assets.resolve(coin)?;
+
+        let limit_px = self.slippage_price(info, &asset, is_buy, slippage).await?;
+
+        let order = OrderRequest {
+            asset: asset.id,
+            is_buy,
+            limit_px,
+            sz: parse_size(sz, asset.sz_decimals),
+            reduce_only: false,
+            order_type: OrderType::Limit(Limit { tif: Tif::Ioc }),
+            cloid: None,
+        };
+
+        self.place_order(wallet, vec![order], vault_address).await
+    }
+
+    /// Close a position at market price, implemented as a reduce-only IOC limit order bounded by `slippage`
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the order with
+    /// * `info` - Used to fetch the current mid price and the open position
+    /// * `assets` - Used to resolve `coin` to its asset id and size decimals
+    /// * `coin` - The coin of the position to close e.g `ETH`
+    /// * `sz` - The size to close, at most the size of the position. If `None`, the whole position is closed
+    /// * `slippage` - The max slippage from the mid price, e.g. `0.05` for 5%
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    #[allow(clippy::too_many_arguments)]
+    pub async fn market_close<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        info: &Info,
+        assets: &AssetRegistry,
+        coin: &str,
+        sz: Option<f64>,
+        slippage: f64,
+        vault_address: Option<Address>,
+    ) -> Result<Response> {
+        let asset = assets.resolve(coin)?;
+
+        let user = self.account_of(&*wallet, vault_address);
+
+        let szi = position_size(info, user, &asset.name).await?;
+
+        let is_buy = szi < 0.0;
+
+        let sz = sz.unwrap_or(szi.abs()).min(szi.abs());
+
+        let limit_px = self.slippage_price(info, &asset, is_buy, slippage).await?;
+
+        let order = OrderRequest {
+            asset: asset.id,
+            is_buy,
+            limit_px,
+            sz: parse_size(sz, asset.sz_decimals),
+            reduce_only: true,
+            order_type: OrderType::Limit(Limit { tif: Tif::Ioc }),
+            cloid: None,
         };
 
-        self.client.post(&API::Exchange, &request).await
+        self.place_order(wallet, vec![order], vault_address).await
     }
 
     /// Place a normal order with tpsl order
@@ -73,35 +201,99 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `orders` - The orders to place
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    /// e.g. `0x0000000000000000000000000000000000000000`
+    ///   e.g. `0x0000000000000000000000000000000000000000`
     ///
     /// # Note
     /// * `cloid` in argument `order` is an optional 128 bit hex string, e.g. `0x1234567890abcdef1234567890abcdef`
-    pub async fn normal_tpsl(
+    pub async fn normal_tpsl<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         orders: Vec<OrderRequest>,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::Order {
             grouping: Grouping::NormalTpsl,
             orders,
+            builder: self.builder.clone(),
         };
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+        self.submit(&request).await
+    }
 
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
+    /// Place take profit / stop loss orders attached to an open position. Unlike
+    /// [`Exchange::normal_tpsl`], the orders track the size of the whole position.
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the order with
+    /// * `info` - Used to look up the open position
+    /// * `assets` - Used to resolve the asset of the orders
+    /// * `orders` - The trigger orders to place, all for the same asset
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    ///
+    /// # Note
+    /// * The orders must be reduce-only trigger orders on the opposite side of the position,
+    ///   otherwise [`Error::InvalidOrder`] is returned without submitting anything
+    pub async fn position_tpsl<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        info: &Info,
+        assets: &AssetRegistry,
+        orders: Vec<OrderRequest>,
+        vault_address: Option<Address>,
+    ) -> Result<Response> {
+        let id = match orders.as_slice() {
+            [] => return Err(Error::InvalidOrder("no orders".to_string())),
+            [first, rest @ ..] if rest.iter().all(|o| o.asset == first.asset) => first.asset,
+            _ => {
+                return Err(Error::InvalidOrder(
+                    "orders must all be for the same asset".to_string(),
+                ))
+            }
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let asset = assets
+            .by_id(id)
+            .ok_or_else(|| Error::AssetNotFound(id.to_string()))?;
+
+        let user = self.account_of(&*wallet, vault_address);
+
+        let szi = position_size(info, user, &asset.name).await?;
+
+        for order in &orders {
+            if !matches!(order.order_type, OrderType::Trigger(_)) {
+                return Err(Error::InvalidOrder(format!(
+                    "{} tpsl must be a trigger order",
+                    asset.name
+                )));
+            }
+
+            if !order.reduce_only {
+                return Err(Error::InvalidOrder(format!(
+                    "{} tpsl must be reduce-only",
+                    asset.name
+                )));
+            }
+
+            if order.is_buy != (szi < 0.0) {
+                return Err(Error::InvalidOrder(format!(
+                    "{} tpsl must be on the opposite side of the position",
+                    asset.name
+                )));
+            }
+        }
+
+        let action = Action::Order {
+            grouping: Grouping::PositionTpsl,
+            orders,
+            builder: self.builder.clone(),
+        };
+
+        let request = self.sign_action(wallet, action, vault_address).await?;
+
+        self.submit(&request).await
     }
 
     /// Cancel an order
@@ -110,29 +302,90 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `cancels` - The orders to cancel
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    /// e.g. `0x0000000000000000000000000000000000000000`
-    pub async fn cancel_order(
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn cancel_order<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         cancels: Vec<CancelRequest>,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::Cancel { cancels };
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
-
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
+        self.submit(&request).await
+    }
 
-        self.client.post(&API::Exchange, &request).await
+    /// Cancel all open orders matching a filter
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the cancels with
+    /// * `info` - Used to look up the open orders
+    /// * `assets` - Used to resolve the asset of each open order
+    /// * `filter` - Which orders to cancel, use `CancelFilter::default()` to cancel every order
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    ///
+    /// # Note
+    /// * Orders are cancelled in batches of up to 100, a batch rejected by the exchange or
+    ///   failing to be sent is reported as failed for each of its orders and the remaining
+    ///   batches are still sent
+    /// * Orders of a coin missing from `assets` are reported as failed, without an asset
+    pub async fn cancel_all<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        info: &Info,
+        assets: &AssetRegistry,
+        filter: CancelFilter,
+        vault_address: Option<Address>,
+    ) -> Result<Vec<CancelResult>> {
+        let user = self.account_of(&*wallet, vault_address);
+
+        let mut results = Vec::new();
+        let mut cancels = Vec::new();
+
+        for order in info.frontend_open_orders(user).await? {
+            if !filter.matches(&order) {
+                continue;
+            }
+
+            match assets.asset(&order.coin) {
+                Ok(asset) => cancels.push(CancelRequest {
+                    asset,
+                    oid: order.oid,
+                }),
+                Err(e) => results.push(CancelResult {
+                    asset: None,
+                    oid: order.oid,
+                    error: Some(e.to_string()),
+                }),
+            }
+        }
+
+        for batch in cancels.chunks(CANCEL_BATCH_SIZE) {
+            let batch_results = self
+                .cancel_order(wallet.clone(), batch.to_vec(), vault_address)
+                .await
+                .and_then(|response| response.into_cancel_results(batch));
+
+            match batch_results {
+                Ok(batch_results) => results.extend(batch_results),
+                Err(e) => {
+                    let error = match e {
+                        Error::ExchangeError(error) => error,
+                        e => e.to_string(),
+                    };
+
+                    results.extend(batch.iter().map(|cancel| CancelResult {
+                        asset: Some(cancel.asset),
+                        oid: cancel.oid,
+                        error: Some(error.clone()),
+                    }))
+                }
+            }
+        }
+
+        Ok(results)
     }
 
     /// Cancel order(s) by client order id (cloid)
@@ -141,31 +394,20 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `cancels` - The client orders to cancel
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    /// e.g. `0x0000000000000000000000000000000000000000`
+    ///   e.g. `0x0000000000000000000000000000000000000000`
     ///
     /// Note: `cloid` in argument `cancel` is a 128 bit hex string, e.g. `0x1234567890abcdef1234567890abcdef`
-    pub async fn cancel_order_by_cloid(
+    pub async fn cancel_order_by_cloid<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         cancels: Vec<CancelByCloidRequest>,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::CancelByCloid { cancels };
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
-
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Modify an order
@@ -174,31 +416,24 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `order` - The orders to modify
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    /// e.g. `0x0000000000000000000000000000000000000000`
+    ///   e.g. `0x0000000000000000000000000000000000000000`
     ///
     /// Note: `cloid` in argument `order` is an optional 128 bit hex string, e.g. `0x1234567890abcdef1234567890abcdef`
-    pub async fn modify_order(
+    pub async fn modify_order<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         order: ModifyRequest,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
-        let action = Action::Modify(order);
-
-        let connection_id = action.connection_id(vault_address, nonce)?;
-
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
+        let action = Action::Modify {
+            oid: order.oid,
+            order: order.order,
+            builder: self.builder.clone(),
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let request = self.sign_action(wallet, action, vault_address).await?;
+
+        self.submit(&request).await
     }
 
     /// Batch modify orders
@@ -207,29 +442,21 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `orders` - The orders to modify
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    /// e.g. `0x0000000000000000000000000000000000000000`
-    pub async fn batch_modify_orders(
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn batch_modify_orders<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         orders: Vec<ModifyRequest>,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
-        let action = Action::BatchModify { modifies: orders };
-
-        let connection_id = action.connection_id(vault_address, nonce)?;
-
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
+        let action = Action::BatchModify {
+            modifies: orders,
+            builder: self.builder.clone(),
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let request = self.sign_action(wallet, action, vault_address).await?;
+
+        self.submit(&request).await
     }
 
     /// Update cross or isolated leverage on a coin
@@ -239,35 +466,25 @@ impl Exchange {
     /// * `leverage` - The new leverage to set
     /// * `asset` - The asset to set the leverage for
     /// * `is_cross` - true if cross leverage, false if isolated leverage
-    pub async fn update_leverage(
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn update_leverage<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         leverage: u32,
         asset: u32,
         is_cross: bool,
+        vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::UpdateLeverage {
             asset,
             is_cross,
             leverage,
         };
 
-        let vault_address = None;
-
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
-
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Add or remove margin from isolated position
@@ -277,35 +494,25 @@ impl Exchange {
     /// * `asset` - The asset to set the margin for
     /// * `is_buy` - true if adding margin, false if removing margin
     /// * `ntli` - The new margin to set
-    pub async fn update_isolated_margin(
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn update_isolated_margin<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         asset: u32,
         is_buy: bool,
         ntli: i64,
+        vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::UpdateIsolatedMargin {
             asset,
             is_buy,
             ntli,
         };
 
-        let vault_address = None;
-
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
-
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Place a TWAP order
@@ -313,29 +520,39 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the order with
     /// * `twap` - The twap order to place
     /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
-    /// e.g. `0x0000000000000000000000000000000000000000`
-    pub async fn twap_order(
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn twap_order<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         twap: TwapRequest,
         vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::TwapOrder { twap };
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+        self.submit(&request).await
+    }
 
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
+    /// Cancel a running TWAP order
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the cancel with
+    /// * `asset` - The asset index of the TWAP order
+    /// * `twap_id` - The id of the TWAP order, as returned by [`Exchange::twap_order`]
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn cancel_twap<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        asset: u32,
+        twap_id: u64,
+        vault_address: Option<Address>,
+    ) -> Result<Response> {
+        let action = Action::TwapCancel { asset, twap_id };
+
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Send usd to another address. This transfer does not touch the EVM bridge. The signature
@@ -345,19 +562,15 @@ impl Exchange {
     /// * `from` - The wallet to sign the transfer with
     /// * `destination` - The address to send the usd to
     /// * `amount` - The amount of usd to send
-    pub async fn usdc_transfer(
+    pub async fn usdc_transfer<S: Signer>(
         &self,
-        from: Arc<LocalWallet>,
+        from: Arc<S>,
         destination: Address,
         amount: String,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
+        let nonce = self.next_nonce(from.address())?;
 
-        let hyperliquid_chain = match self.chain {
-            Chain::Arbitrum => HyperliquidChain::Mainnet,
-            Chain::ArbitrumTestnet => HyperliquidChain::Testnet,
-            _ => return Err(Error::ChainNotSupported(self.chain.to_string())),
-        };
+        let hyperliquid_chain = self.hyperliquid_chain()?;
 
         let payload = UsdSend {
             signature_chain_id: 421614.into(),
@@ -367,18 +580,161 @@ impl Exchange {
             time: nonce,
         };
 
-        let signature = from.sign_typed_data(&payload).await?;
-
         let action = Action::UsdSend(payload);
 
-        let request = Request {
-            action,
+        let request = self.sign_action(from, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Send spot assets to another address. This transfer does not touch the EVM bridge. The signature
+    /// format is human readable for wallet interfaces.
+    ///
+    /// # Arguments
+    /// * `from` - The wallet to sign the transfer with
+    /// * `destination` - The address to send the tokens to
+    /// * `token` - The token to send in the format `name:tokenId`, e.g. `PURR:0xc1fb593aeffbeb02f85e0308e9956a90`
+    /// * `amount` - The amount of tokens to send
+    pub async fn spot_send<S: Signer>(
+        &self,
+        from: Arc<S>,
+        destination: Address,
+        token: String,
+        amount: String,
+    ) -> Result<Response> {
+        let nonce = self.next_nonce(from.address())?;
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        let payload = SpotSend {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            destination: to_checksum(&destination, None),
+            token,
+            amount,
+            time: nonce,
+        };
+
+        let action = Action::SpotSend(payload);
+
+        let request = self.sign_action(from, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Move usdc between the spot and perp balances of the signer. The signature format is human
+    /// readable for wallet interfaces.
+    ///
+    /// # Arguments
+    /// * `from` - The wallet to sign the transfer with
+    /// * `amount` - The amount of usdc to move
+    /// * `to_perp` - true to move usdc from spot to perp, false to move it from perp to spot
+    pub async fn usd_class_transfer<S: Signer>(
+        &self,
+        from: Arc<S>,
+        amount: String,
+        to_perp: bool,
+    ) -> Result<Response> {
+        let nonce = self.next_nonce(from.address())?;
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        let payload = UsdClassTransfer {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            amount,
+            to_perp,
+            nonce,
+        };
+
+        let action = Action::UsdClassTransfer(payload);
+
+        let request = self.sign_action(from, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Move HYPE from the spot balance into the staking balance
+    ///
+    /// # Arguments
+    /// * `from` - The wallet to sign the deposit with
+    /// * `wei` - The amount of HYPE in wei, i.e. 1 HYPE = 100_000_000 wei
+    pub async fn staking_deposit<S: Signer>(&self, from: Arc<S>, wei: u64) -> Result<Response> {
+        let nonce = self.next_nonce(from.address())?;
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        let payload = CDeposit {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            wei,
+            nonce,
+        };
+
+        let action = Action::CDeposit(payload);
+
+        let request = self.sign_action(from, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Move HYPE from the staking balance back into the spot balance. Withdrawals go through an
+    /// unstaking queue before they are credited.
+    ///
+    /// # Arguments
+    /// * `from` - The wallet to sign the withdrawal with
+    /// * `wei` - The amount of HYPE in wei, i.e. 1 HYPE = 100_000_000 wei
+    pub async fn staking_withdraw<S: Signer>(&self, from: Arc<S>, wei: u64) -> Result<Response> {
+        let nonce = self.next_nonce(from.address())?;
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        let payload = CWithdraw {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            wei,
+            nonce,
+        };
+
+        let action = Action::CWithdraw(payload);
+
+        let request = self.sign_action(from, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Delegate staked HYPE to a validator, or undelegate it
+    ///
+    /// # Arguments
+    /// * `from` - The wallet to sign the delegation with
+    /// * `validator` - The validator's address in 42-character hexadecimal format
+    /// * `wei` - The amount of HYPE in wei, i.e. 1 HYPE = 100_000_000 wei
+    /// * `is_undelegate` - true to undelegate from the validator
+    pub async fn token_delegate<S: Signer>(
+        &self,
+        from: Arc<S>,
+        validator: Address,
+        wei: u64,
+        is_undelegate: bool,
+    ) -> Result<Response> {
+        let nonce = self.next_nonce(from.address())?;
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        let payload = TokenDelegate {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            validator,
+            wei,
+            is_undelegate,
             nonce,
-            signature,
-            vault_address: None,
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let action = Action::TokenDelegate(payload);
+
+        let request = self.sign_action(from, action, None).await?;
+
+        self.submit(&request).await
     }
 
     /// Withdraw from bridge
@@ -387,19 +743,15 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the withdrawal with
     /// * `destination` - The address to send the usd to
     /// * `amount` - The amount of usd to send
-    pub async fn withdraw_from_bridge(
+    pub async fn withdraw_from_bridge<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         destination: Address,
         amount: String,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
+        let nonce = self.next_nonce(wallet.address())?;
 
-        let hyperliquid_chain = match self.chain {
-            Chain::Arbitrum => HyperliquidChain::Mainnet,
-            Chain::ArbitrumTestnet => HyperliquidChain::Testnet,
-            _ => return Err(Error::ChainNotSupported(self.chain.to_string())),
-        };
+        let hyperliquid_chain = self.hyperliquid_chain()?;
 
         let payload = Withdraw3 {
             hyperliquid_chain,
@@ -409,18 +761,11 @@ impl Exchange {
             time: nonce,
         };
 
-        let signature = wallet.sign_typed_data(&payload).await?;
-
         let action = Action::Withdraw3(payload);
 
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address: None,
-        };
+        let request = self.sign_action(wallet, action, None).await?;
 
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Approve an agent to trade on behalf of the user
@@ -429,19 +774,15 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the approval with
     /// * `agent_address` - The address of the agent to approve
     /// * `agent_name` - An optional name for the agent
-    pub async fn approve_agent(
+    pub async fn approve_agent<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         agent_address: Address,
         agent_name: Option<String>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
+        let nonce = self.next_nonce(wallet.address())?;
 
-        let hyperliquid_chain = match self.chain {
-            Chain::Arbitrum => HyperliquidChain::Mainnet,
-            Chain::ArbitrumTestnet => HyperliquidChain::Testnet,
-            _ => return Err(Error::ChainNotSupported(self.chain.to_string())),
-        };
+        let hyperliquid_chain = self.hyperliquid_chain()?;
 
         let agent = ApproveAgent {
             hyperliquid_chain,
@@ -451,18 +792,42 @@ impl Exchange {
             agent_name,
         };
 
-        let signature = wallet.sign_typed_data(&agent).await?;
-
         let action = Action::ApproveAgent(agent);
 
-        let request = Request {
-            action,
+        let request = self.sign_action(wallet, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Approve a builder to charge a fee on orders it places on behalf of the user
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the approval with
+    /// * `builder` - The address of the builder to approve
+    /// * `max_fee_rate` - The max fee the builder may charge as a percentage, e.g. `0.01%` for 1 basis point
+    pub async fn approve_builder_fee<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        builder: Address,
+        max_fee_rate: String,
+    ) -> Result<Response> {
+        let nonce = self.next_nonce(wallet.address())?;
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        let payload = ApproveBuilderFee {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            max_fee_rate,
+            builder,
             nonce,
-            signature,
-            vault_address: None,
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let action = Action::ApproveBuilderFee(payload);
+
+        let request = self.sign_action(wallet, action, None).await?;
+
+        self.submit(&request).await
     }
 
     /// Create subaccount for the user
@@ -470,29 +835,19 @@ impl Exchange {
     /// # Arguments
     /// * `wallet` - The wallet to create the subaccount with
     /// * `name` - The name of the subaccount
-    pub async fn create_sub_account(
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn create_sub_account<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         name: String,
+        vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::CreateSubAccount { name };
 
-        let vault_address = None;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
-
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
-
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Rename subaccount
@@ -501,158 +856,679 @@ impl Exchange {
     /// * `wallet` - The wallet to sign the rename with
     /// * `name` - The new name of the subaccount
     /// * `sub_account_user` - The address of the subaccount to rename
-    pub async fn sub_account_modify(
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn sub_account_modify<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         name: String,
         sub_account_user: Address,
+        vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::SubAccountModify {
             name,
             sub_account_user,
         };
 
-        let vault_address = None;
-
-        let connection_id = action.connection_id(vault_address, nonce)?;
-
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
-
-        let request = Request {
-            action,
-            nonce,
-            signature,
-            vault_address,
-        };
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        self.client.post(&API::Exchange, &request).await
+        self.submit(&request).await
     }
 
     /// Transfer funds between subaccounts
     ///
     /// # Arguments
     /// * `wallet` - The wallet to sign the transfer with
-    /// * `from` - The subaccount to transfer from
-    pub async fn sub_account_transfer(
+    /// * `is_deposit` - true if depositing into the subaccount, false if withdrawing from it
+    /// * `sub_account_user` - The address of the subaccount
+    /// * `usd` - The amount of usd to transfer
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn sub_account_transfer<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         is_deposit: bool,
         sub_account_user: Address,
         usd: u64,
+        vault_address: Option<Address>,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
-
         let action = Action::SubAccountTransfer {
             is_deposit,
             sub_account_user,
             usd,
         };
 
-        let vault_address = None;
+        let request = self.sign_action(wallet, action, vault_address).await?;
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        self.submit(&request).await
+    }
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+    /// Set referrer for the user
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the transfer with
+    /// * `code` - The referrer code
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn set_referrer<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        code: String,
+        vault_address: Option<Address>,
+    ) -> Result<Response> {
+        let action = Action::SetReferrer { code };
 
-        let request = Request {
-            action,
-            nonce,
-            signature,
+        let request = self.sign_action(wallet, action, vault_address).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Schedule a time in (UTC ms) to cancel all open orders
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the transaction with
+    /// * `time` - Optional time in milliseconds to cancel all open orders
+    /// * `vault_address` - If acting on behalf of a vault or sub-account, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    ///
+    /// # Note
+    /// * If `time` is `None`, then unsets any cancel time in the future.
+    ///   `time` must be atleast 5 seconds after the current time
+    /// * Once the time is reached, all open orders will be cancelled and trigger count will be incremented.
+    ///   The max number of triggers is 10 per day. Trigger count resets at 00:00 UTC
+    pub async fn schedule_cancel<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        time: Option<u64>,
+        vault_address: Option<Address>,
+    ) -> Result<Response> {
+        let action = Action::ScheduleCancel { time };
+
+        let request = self.sign_action(wallet, action, vault_address).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Deposit into or withdraw from a vault
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the transfer with
+    /// * `vault_address` - The address of the vault
+    /// * `is_deposit` - true if depositing into the vault, false if withdrawing from it
+    /// * `usd` - The amount of usd to transfer, e.g. `1_000_000` for 1 USD
+    pub async fn vault_transfer<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        vault_address: Address,
+        is_deposit: bool,
+        usd: u64,
+    ) -> Result<Response> {
+        let action = Action::VaultTransfer {
             vault_address,
+            is_deposit,
+            usd,
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let request = self.sign_action(wallet, action, None).await?;
+
+        self.submit(&request).await
     }
 
-    /// Set referrer for the user
+    /// Create a vault led by the user
     ///
     /// # Arguments
-    /// * `wallet` - The wallet to sign the transfer with
-    /// * `code` - The referrer code
-    pub async fn set_referrer(&self, wallet: Arc<LocalWallet>, code: String) -> Result<Response> {
-        let nonce = self.nonce()?;
+    /// * `wallet` - The wallet of the vault leader
+    /// * `name` - The name of the vault
+    /// * `description` - The description of the vault
+    /// * `initial_usd` - The initial deposit of the leader, e.g. `100_000_000` for 100 USD
+    pub async fn create_vault<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        name: String,
+        description: String,
+        initial_usd: u64,
+    ) -> Result<Response> {
+        let nonce = self.next_nonce(wallet.address())?;
 
-        let action = Action::SetReferrer { code };
+        let action = Action::CreateVault {
+            name,
+            description,
+            initial_usd,
+            nonce,
+        };
+
+        let request = self.sign_action(wallet, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Distribute funds from a vault to its followers, pro rata to their equity
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet of the vault leader
+    /// * `vault_address` - The address of the vault
+    /// * `usd` - The amount of usd to distribute, e.g. `1_000_000` for 1 USD
+    pub async fn vault_distribute<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        vault_address: Address,
+        usd: u64,
+    ) -> Result<Response> {
+        let action = Action::VaultDistribute { vault_address, usd };
+
+        let request = self.sign_action(wallet, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Convert the signer into a multi-sig user. Once converted, its actions must be signed by
+    /// `threshold` of `authorized_users` and submitted with [`Exchange::submit_multi_sig`].
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet of the user to convert
+    /// * `signers` - The authorized users and the number of them that must sign each action
+    pub async fn convert_to_multi_sig_user<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        mut signers: MultiSigSigners,
+    ) -> Result<Response> {
+        if signers.threshold == 0 || signers.threshold > signers.authorized_users.len() {
+            return Err(Error::InvalidSigners(format!(
+                "threshold {} of {} authorized users",
+                signers.threshold,
+                signers.authorized_users.len()
+            )));
+        }
 
-        let vault_address = None;
+        signers.authorized_users.sort();
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+        let nonce = self.next_nonce(wallet.address())?;
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+        let hyperliquid_chain = self.hyperliquid_chain()?;
 
-        let request = Request {
+        let payload = ConvertToMultiSigUser {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain,
+            signers: serde_json::to_string(&signers)?,
+            nonce,
+        };
+
+        let action = Action::ConvertToMultiSigUser(payload);
+
+        let request = self.sign_action(wallet, action, None).await?;
+
+        self.submit(&request).await
+    }
+
+    /// Start collecting signatures for an action of a multi-sig user. The returned bundle is
+    /// passed to each authorized user to sign with [`Exchange::sign_multi_sig`].
+    ///
+    /// # Arguments
+    /// * `multi_sig_user` - The address of the multi-sig user
+    /// * `outer_signer` - The authorized user that will submit the action
+    /// * `signers` - The authorized users of the multi-sig user and its threshold
+    /// * `action` - The action to perform on behalf of the multi-sig user
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub fn multi_sig_bundle(
+        &self,
+        multi_sig_user: Address,
+        outer_signer: Address,
+        signers: MultiSigSigners,
+        action: Action,
+        vault_address: Option<Address>,
+    ) -> Result<MultiSigBundle> {
+        let nonce = match action.nonce() {
+            Some(nonce) => nonce,
+            None => self.next_nonce(multi_sig_user)?,
+        };
+
+        Ok(MultiSigBundle {
+            signature_chain_id: 421614.into(),
+            hyperliquid_chain: self.hyperliquid_chain()?,
+            multi_sig_user,
+            outer_signer,
+            signers,
             action,
             nonce,
-            signature,
             vault_address,
-        };
+            expires_after: self.expiry(nonce),
+            signatures: vec![],
+        })
+    }
+
+    /// Sign a multi-sig bundle as one of the authorized users, e.g. on an offline machine
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet of an authorized user
+    /// * `bundle` - The bundle to add the signature to
+    pub async fn sign_multi_sig<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        bundle: &mut MultiSigBundle,
+    ) -> Result<()> {
+        let signature = sign_user_action(wallet, &bundle.typed_data()?).await?;
+
+        bundle.add_signature(signature)?;
 
-        self.client.post(&API::Exchange, &request).await
+        Ok(())
     }
 
-    /// Schedule a time in (UTC ms) to cancel all open orders
+    /// Sign a multi-sig bundle as its outer signer once enough authorized users signed it,
+    /// without submitting it
     ///
     /// # Arguments
-    /// * `wallet` - The wallet to sign the transaction with
-    /// * `time` - Optional time in milliseconds to cancel all open orders
+    /// * `wallet` - The wallet of the bundle's outer signer
+    /// * `bundle` - The signed bundle
+    pub async fn finalize_multi_sig<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        bundle: MultiSigBundle,
+    ) -> Result<Request> {
+        if wallet.address() != bundle.outer_signer {
+            return Err(Error::UnauthorizedSigner(wallet.address()));
+        }
+
+        let hyperliquid_chain = self.hyperliquid_chain()?;
+
+        if bundle.hyperliquid_chain != hyperliquid_chain {
+            return Err(Error::ChainMismatch(
+                bundle.hyperliquid_chain.to_string(),
+                hyperliquid_chain.to_string(),
+            ));
+        }
+
+        let expires_after = bundle.expires_after;
+        let (action, nonce, vault_address) = bundle.into_action()?;
+
+        self.sign_action_with_nonce(wallet, action, nonce, vault_address, expires_after)
+            .await
+    }
+
+    /// Submit a multi-sig bundle once enough authorized users signed it
     ///
-    /// # Note
-    /// * If `time` is `None`, then unsets any cancel time in the future.
-    /// `time` must be atleast 5 seconds after the current time
-    /// * Once the time is reached, all open orders will be cancelled and trigger count will be incremented.
-    /// The max number of triggers is 10 per day. Trigger count resets at 00:00 UTC
-    pub async fn schedule_cancel(
+    /// # Arguments
+    /// * `wallet` - The wallet of the bundle's outer signer
+    /// * `bundle` - The signed bundle
+    pub async fn submit_multi_sig<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
-        time: Option<u64>,
+        wallet: Arc<S>,
+        bundle: MultiSigBundle,
     ) -> Result<Response> {
-        let nonce = self.nonce()?;
+        let request = self.finalize_multi_sig(wallet, bundle).await?;
 
-        let time = time.unwrap_or_else(|| nonce + 5000);
+        self.submit(&request).await
+    }
 
-        let action = Action::ScheduleCancel { time };
+    /// Sign an action without submitting it. The signed request can be serialized, e.g. to move it
+    /// from an offline machine to one that is online, and submitted later with [`Exchange::submit`].
+    ///
+    /// Actions are signed with a new nonce, unless they carry their own (see [`Action::nonce`]).
+    /// User signed actions (e.g. `UsdSend`, `SpotSend`, `Withdraw3`, `ApproveAgent`) are never signed
+    /// on behalf of a vault.
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the action with
+    /// * `action` - The action to sign
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn sign_action<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        action: Action,
+        vault_address: Option<Address>,
+    ) -> Result<Request> {
+        let nonce = match action.nonce() {
+            Some(nonce) => nonce,
+            None => self.next_nonce(wallet.address())?,
+        };
 
-        let vault_address = None;
+        self.sign_action_with_nonce(wallet, action, nonce, vault_address, self.expiry(nonce))
+            .await
+    }
 
-        let connection_id = action.connection_id(vault_address, nonce)?;
+    /// Same as [`Exchange::sign_action`], with an expiry for this action only instead of the
+    /// one set with [`Exchange::set_expires_after`]
+    ///
+    /// # Arguments
+    /// * `wallet` - The wallet to sign the action with
+    /// * `action` - The action to sign
+    /// * `vault_address` - If trading on behalf of a vault, its onchain address in 42-character hexadecimal format
+    ///   e.g. `0x0000000000000000000000000000000000000000`
+    /// * `expires_after` - Timestamp in milliseconds after which the exchange rejects the action,
+    ///   or `None` to never expire. User signed actions are not affected.
+    pub async fn sign_action_with_expiry<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        action: Action,
+        vault_address: Option<Address>,
+        expires_after: Option<u64>,
+    ) -> Result<Request> {
+        let nonce = match action.nonce() {
+            Some(nonce) => nonce,
+            None => self.next_nonce(wallet.address())?,
+        };
 
-        let signature = self.sign_l1_action(wallet, connection_id).await?;
+        self.sign_action_with_nonce(wallet, action, nonce, vault_address, expires_after)
+            .await
+    }
 
-        let request = Request {
+    async fn sign_action_with_nonce<S: Signer>(
+        &self,
+        wallet: Arc<S>,
+        action: Action,
+        nonce: u64,
+        vault_address: Option<Address>,
+        expires_after: Option<u64>,
+    ) -> Result<Request> {
+        let typed_data = action
+            .as_user_signed()
+            .map(|payload| payload.typed_data())
+            .transpose()?;
+
+        let (signature, vault_address, expires_after) = match (&action, typed_data) {
+            (_, Some(typed_data)) => (sign_user_action(wallet, &typed_data).await?, None, None),
+            (Action::MultiSig(multi_sig), None) => {
+                let payload = SendMultiSig {
+                    signature_chain_id: multi_sig.signature_chain_id,
+                    hyperliquid_chain: self.hyperliquid_chain()?,
+                    multi_sig_action_hash: multi_sig.action_hash(
+                        vault_address,
+                        nonce,
+                        expires_after,
+                    )?,
+                    nonce,
+                };
+
+                (
+                    sign_user_action(wallet, &payload).await?,
+                    vault_address,
+                    expires_after,
+                )
+            }
+            _ => {
+                let connection_id = action.connection_id(vault_address, nonce, expires_after)?;
+
+                let signature = self.sign_l1_action(wallet, connection_id).await?;
+
+                (signature, vault_address, expires_after)
+            }
+        };
+
+        Ok(Request {
             action,
             nonce,
             signature,
             vault_address,
+            expires_after,
+        })
+    }
+
+    /// Submit a request signed with [`Exchange::sign_action`]
+    ///
+    /// A request rejected by the exchange is returned as an error, e.g.
+    /// [`Error::InvalidNonce`], or [`Error::ExchangeError`] for failures without their own
+    /// variant (see [`Error::from_response`]).
+    ///
+    /// With a retry policy, a submission failing with a transient error is retried with
+    /// exponential backoff. If the request may have reached the exchange, it's only resubmitted
+    /// when that can't execute it twice (see [`resubmission`]): orders need a cloid, and are
+    /// resubmitted only once the exchange reports none of their cloids. Otherwise, the error
+    /// is returned, or [`Error::SubmissionLanded`] if the orders were placed, or
+    /// [`Error::SubmissionUnconfirmed`] if their status couldn't be queried.
+    ///
+    /// The request is submitted for its vault address if any, or else for the account set with
+    /// [`Exchange::set_account`], see [`Exchange::submit_for_account`]. Without an account,
+    /// orders are never resubmitted since their status can't be queried, and actions are
+    /// counted against the per-address limit of the signer.
+    ///
+    /// # Arguments
+    /// * `request` - The signed request
+    pub async fn submit(&self, request: &Request) -> Result<Response> {
+        self.submit_as(request, request.vault_address.or(self.account))
+            .await
+    }
+
+    /// Submit a request signed with [`Exchange::sign_action`] for `account`, see
+    /// [`Exchange::submit`].
+    ///
+    /// The account is the master account when signing with an agent wallet. The status of
+    /// orders is queried for it before resubmitting them, and its actions are counted against
+    /// the per-address limit of the rate limiter set on the client, if any.
+    ///
+    /// # Arguments
+    /// * `request` - The signed request
+    /// * `account` - The account the request acts for, i.e. its vault address if any
+    pub async fn submit_for_account(
+        &self,
+        request: &Request,
+        account: Address,
+    ) -> Result<Response> {
+        self.submit_as(request, Some(account)).await
+    }
+
+    async fn submit_as(&self, request: &Request, account: Option<Address>) -> Result<Response> {
+        let Some(policy) = &self.retry_policy else {
+            return self.post(request, account).await;
         };
 
-        self.client.post(&API::Exchange, &request).await
+        let mut retry = 0;
+
+        loop {
+            let error = match self.post(request, account).await {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            if retry >= policy.max_retries || !error.is_transient() {
+                return Err(error);
+            }
+
+            tokio::time::sleep(policy.backoff(retry)).await;
+            retry += 1;
+
+            if error.is_not_executed() {
+                continue;
+            }
+
+            match resubmission(&request.action) {
+                Resubmission::Always => {}
+                Resubmission::IfOrdersUnknown(cloids) => {
+                    let Some(account) = account else {
+                        return Err(error);
+                    };
+
+                    let oids = match self.placed_orders(account, cloids).await {
+                        Ok(oids) => oids,
+                        Err(lookup) => {
+                            return Err(Error::SubmissionUnconfirmed(
+                                Box::new(error),
+                                Box::new(lookup),
+                            ))
+                        }
+                    };
+
+                    if !oids.is_empty() {
+                        return Err(Error::SubmissionLanded(oids));
+                    }
+                }
+                Resubmission::Never => return Err(error),
+            }
+        }
+    }
+
+    /// Post `request`, counting its actions against the per-address limit of `account`, or of
+    /// the signer without an account
+    async fn post(&self, request: &Request, account: Option<Address>) -> Result<Response> {
+        if let Some(rate_limiter) = self.client.rate_limiter() {
+            let address = match account {
+                Some(account) => account,
+                None => request.recover_signer(self.hyperliquid_chain()?)?,
+            };
+
+            rate_limiter
+                .acquire_actions(address, request.actions())
+                .await?;
+        }
+
+        self.client.post(&API::Exchange, request).await
+    }
+
+    /// Ids of the orders of `user` the exchange knows by cloid
+    async fn placed_orders(&self, user: Address, cloids: Vec<Cloid>) -> Result<Vec<u64>> {
+        let mut oids = vec![];
+
+        for cloid in cloids {
+            let status: OrderStatus = self
+                .client
+                .post(
+                    &API::Info,
+                    &InfoRequest::OrderStatus {
+                        user,
+                        oid: Oid::Cloid(cloid),
+                    },
+                )
+                .await?;
+
+            if let Some(order) = status.order {
+                oids.push(order.order.oid as u64);
+            }
+        }
+
+        Ok(oids)
+    }
+
+    /// Retry submissions failing with a transient error, see [`Exchange::submit`]
+    pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Submit requests without a vault address for `account`, e.g. the master account of the
+    /// agent wallet they are signed with, see [`Exchange::submit_for_account`]
+    pub fn set_account(&mut self, account: Option<Address>) {
+        self.account = account;
     }
 
-    async fn sign_l1_action(
+    /// Account `wallet` trades for: `vault_address` if any, or else the account set with
+    /// [`Exchange::set_account`], or else the wallet itself
+    fn account_of<S: Signer>(&self, wallet: &S, vault_address: Option<Address>) -> Address {
+        vault_address
+            .or(self.account)
+            .unwrap_or_else(|| wallet.address())
+    }
+
+    /// The Hyperliquid chain user signed actions (e.g. `UsdSend`) must be signed for
+    pub fn hyperliquid_chain(&self) -> Result<HyperliquidChain> {
+        match self.chain {
+            Chain::Arbitrum => Ok(HyperliquidChain::Mainnet),
+            Chain::ArbitrumTestnet => Ok(HyperliquidChain::Testnet),
+            _ => Err(Error::ChainNotSupported(self.chain.to_string())),
+        }
+    }
+
+    async fn sign_l1_action<S: Signer>(
         &self,
-        wallet: Arc<LocalWallet>,
+        wallet: Arc<S>,
         connection_id: H256,
     ) -> Result<Signature> {
-        let source = match self.chain {
-            Chain::Arbitrum => "a".to_string(),
-            Chain::ArbitrumTestnet => "b".to_string(),
-            _ => return Err(Error::ChainNotSupported(self.chain.to_string())),
-        };
-
         let payload = l1::Agent {
-            source,
+            source: l1::source(self.hyperliquid_chain()?),
             connection_id,
         };
 
-        Ok(wallet.sign_typed_data(&payload).await?)
+        wallet
+            .sign_typed_data(&payload)
+            .await
+            .map_err(|e| Error::SignerError(e.to_string()))
+    }
+
+    /// Mid price of `asset` moved by `slippage` against the order side, rounded to a valid tick
+    /// without exceeding the slippage
+    async fn slippage_price(
+        &self,
+        info: &Info,
+        asset: &AssetInfo,
+        is_buy: bool,
+        slippage: f64,
+    ) -> Result<Number> {
+        let mids = info.mids().await?;
+
+        let mid = mids
+            .get(&asset.name)
+            .ok_or_else(|| Error::MidNotFound(asset.name.clone()))?
+            .parse::<f64>()?;
+
+        let px = if is_buy {
+            mid * (1.0 + slippage)
+        } else {
+            mid * (1.0 - slippage)
+        };
+
+        let mode = if is_buy {
+            RoundingMode::Down
+        } else {
+            RoundingMode::Up
+        };
+
+        Ok(round_price(px, asset.kind, asset.sz_decimals, mode))
     }
 
-    /// get the next nonce to use
-    fn nonce(&self) -> Result<u64> {
-        let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+    /// Use a custom nonce provider, e.g. to share one between several `Exchange` instances
+    /// signing with the same wallet
+    pub fn set_nonce_provider(&mut self, nonce_provider: Arc<dyn NonceProvider>) {
+        self.nonce_provider = nonce_provider;
+    }
+
+    /// Reject each L1 action (orders, cancels, leverage updates, ...) signed from now on once
+    /// `expires_after` has passed since its nonce, e.g. so a stale order stuck in a retry path
+    /// is never filled. User signed actions are not affected.
+    ///
+    /// Use [`Exchange::sign_action_with_expiry`] to set the expiry of a single action.
+    ///
+    /// # Arguments
+    /// * `expires_after` - Time to live of each action, or `None` to never expire
+    pub fn set_expires_after(&mut self, expires_after: Option<Duration>) {
+        self.expires_after = expires_after;
+    }
+
+    /// Expiry timestamp of an action signed with `nonce`, a timestamp in milliseconds
+    fn expiry(&self, nonce: u64) -> Option<u64> {
+        self.expires_after
+            .map(|ttl| nonce.saturating_add(ttl.as_millis() as u64))
+    }
+
+    /// Route orders and modifies through `builder`, which must have been approved by the user
+    /// with [`Exchange::approve_builder_fee`], or through no builder if `None`
+    pub fn set_builder(&mut self, builder: Option<Builder>) {
+        self.builder = builder;
+    }
 
-        Ok(now.as_millis() as u64)
+    /// Get the next nonce to use for actions signed by `signer`
+    pub fn next_nonce(&self, signer: Address) -> Result<u64> {
+        self.nonce_provider.next_nonce(signer)
     }
 }
+
+async fn sign_user_action<S: Signer, T: Eip712 + Send + Sync>(
+    wallet: Arc<S>,
+    payload: &T,
+) -> Result<Signature> {
+    wallet
+        .sign_typed_data(payload)
+        .await
+        .map_err(|e| Error::SignerError(e.to_string()))
+}
+
+/// Signed size of the open position of `user` in `coin`, negative if short
+async fn position_size(info: &Info, user: Address, coin: &str) -> Result<f64> {
+    let state = info.user_state(user).await?;
+
+    state
+        .asset_positions
+        .iter()
+        .find(|p| p.position.coin == coin)
+        .map(|p| p.position.szi.parse::<f64>())
+        .transpose()?
+        .filter(|szi| *szi != 0.0)
+        .ok_or_else(|| Error::NoPosition(coin.to_string()))
+}
@@ -9,9 +9,11 @@ use crate::{
         info::{
             request::{CandleSnapshotRequest, Request},
             response::{
-                AssetContext, CandleSnapshot, FrontendOpenOrders, FundingHistory, L2Book,
-                OpenOrder, OrderStatus, RecentTrades, SpotMeta, SpotMetaAndAssetCtxs, SubAccount,
-                Universe, UserFill, UserFunding, UserSpotState, UserState,
+                AssetContext, CandleSnapshot, Delegation, DelegatorHistory, DelegatorReward,
+                DelegatorSummary, FrontendOpenOrders, FundingHistory, L2Book, OpenOrder,
+                OrderStatus, RecentTrades, SpotMeta, SpotMetaAndAssetCtxs, SubAccount, TwapHistory,
+                TwapSliceFill, Universe, UserFill, UserFunding, UserRateLimit, UserSpotState,
+                UserState, UserVaultEquity, VaultDetails,
             },
         },
         Chain, Oid, API,
@@ -228,6 +230,17 @@ impl Info {
             .await
     }
 
+    /// Retrieve the max fee a user approved a builder to charge, in tenths of a basis point
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    /// * `builder` - The builder's address in 42-character hexadecimal format
+    pub async fn max_builder_fee(&self, user: Address, builder: Address) -> Result<u64> {
+        self.client
+            .post(&API::Info, &Request::MaxBuilderFee { user, builder })
+            .await
+    }
+
     /// Query user sub-accounts
     ///
     /// # Arguments
@@ -262,3 +275,111 @@ impl Info {
             .await
     }
 }
+
+impl Info {
+    /// Retrieve details of a vault
+    ///
+    /// # Arguments
+    /// * `vault_address` - The vault's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    /// * `user` - If set, the `follower_state` of this user is included in the response
+    pub async fn vault_details(
+        &self,
+        vault_address: Address,
+        user: Option<Address>,
+    ) -> Result<VaultDetails> {
+        self.client
+            .post(
+                &API::Info,
+                &Request::VaultDetails {
+                    vault_address,
+                    user,
+                },
+            )
+            .await
+    }
+
+    /// Retrieve a user's equity in each vault they deposited into
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn user_vault_equities(&self, user: Address) -> Result<Vec<UserVaultEquity>> {
+        self.client
+            .post(&API::Info, &Request::UserVaultEquities { user })
+            .await
+    }
+}
+
+impl Info {
+    /// Retrieve a user's fills from TWAP slices
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn user_twap_slice_fills(&self, user: Address) -> Result<Vec<TwapSliceFill>> {
+        self.client
+            .post(&API::Info, &Request::UserTwapSliceFills { user })
+            .await
+    }
+
+    /// Retrieve a user's TWAP history, including the state and status of each TWAP
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn twap_history(&self, user: Address) -> Result<Vec<TwapHistory>> {
+        self.client
+            .post(&API::Info, &Request::TwapHistory { user })
+            .await
+    }
+}
+
+impl Info {
+    /// Retrieve a user's staking delegations
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn delegations(&self, user: Address) -> Result<Vec<Delegation>> {
+        self.client
+            .post(&API::Info, &Request::Delegations { user })
+            .await
+    }
+
+    /// Retrieve a summary of a user's staking balances
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn delegator_summary(&self, user: Address) -> Result<DelegatorSummary> {
+        self.client
+            .post(&API::Info, &Request::DelegatorSummary { user })
+            .await
+    }
+
+    /// Retrieve a user's staking history, i.e. deposits, withdrawals and (un)delegations
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn delegator_history(&self, user: Address) -> Result<Vec<DelegatorHistory>> {
+        self.client
+            .post(&API::Info, &Request::DelegatorHistory { user })
+            .await
+    }
+
+    /// Retrieve a user's staking rewards
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn delegator_rewards(&self, user: Address) -> Result<Vec<DelegatorReward>> {
+        self.client
+            .post(&API::Info, &Request::DelegatorRewards { user })
+            .await
+    }
+
+    /// Retrieve how many actions a user sent and how many its trading volume allows, see
+    /// [`RateLimiter::set_address_limit`](crate::RateLimiter::set_address_limit)
+    ///
+    /// # Arguments
+    /// * `user` - The user's address in 42-character hexadecimal format; e.g. `0x0000000000000000000000000000000000000000`
+    pub async fn user_rate_limit(&self, user: Address) -> Result<UserRateLimit> {
+        self.client
+            .post(&API::Info, &Request::UserRateLimit { user })
+            .await
+    }
+}
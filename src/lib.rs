@@ -1,16 +1,33 @@
 mod api;
+mod assets;
 mod client;
 mod config;
+#[cfg(feature = "decimal")]
+mod decimal;
 mod error;
 mod exchange;
 mod info;
+mod nonce;
+mod rate_limit;
+mod retry;
+mod transport;
 mod websocket;
 
 pub use api::Hyperliquid;
-pub use config::Config;
+pub use assets::{AssetInfo, AssetKind, AssetRegistry, SPOT_ASSET_OFFSET};
+pub use config::{Config, USER_AGENT};
+#[cfg(feature = "decimal")]
+pub use decimal::{from_decimal, ToDecimal};
 pub use error::{Error, Result};
 pub use exchange::Exchange;
 pub use info::Info;
+pub use nonce::{NonceManager, NonceProvider};
+pub use rate_limit::{
+    RateLimitMode, RateLimiter, Weighted, DEFAULT_ACTIONS_PER_ADDRESS, DEFAULT_WEIGHT_PER_MINUTE,
+    THROTTLED_ACTION_INTERVAL,
+};
+pub use retry::{resubmission, Resubmission, RetryPolicy};
+pub use transport::{MemoryTransport, ReqwestTransport, Transport, TransportResponse};
 pub use websocket::Websocket;
 
 pub mod types;
@@ -0,0 +1,107 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        RwLock,
+    },
+    time::SystemTime,
+};
+
+use ethers::types::Address;
+
+use crate::error::Result;
+
+/// Source of nonces for exchange actions.
+///
+/// Hyperliquid rejects an action whose nonce was already used by the same signer, so
+/// implementations must never hand out the same nonce twice for a given signer.
+pub trait NonceProvider: Send + Sync {
+    /// Get the next nonce to use for actions signed by `signer`
+    fn next_nonce(&self, signer: Address) -> Result<u64>;
+}
+
+/// Default [`NonceProvider`].
+///
+/// Nonces are the current time in milliseconds (plus an optional server-time offset),
+/// bumped when needed so that they are strictly increasing per signer. Concurrent calls
+/// for the same signer therefore always get distinct nonces.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    offset: AtomicI64,
+    last: RwLock<HashMap<Address, AtomicU64>>,
+}
+
+impl NonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the offset in milliseconds added to the local clock when generating nonces
+    pub fn set_time_offset(&self, offset: i64) {
+        self.offset.store(offset, Ordering::SeqCst);
+    }
+
+    /// Offset in milliseconds added to the local clock when generating nonces
+    pub fn time_offset(&self) -> i64 {
+        self.offset.load(Ordering::SeqCst)
+    }
+
+    /// Align nonces with the exchange clock
+    ///
+    /// # Arguments
+    /// * `server_time` - The server time in milliseconds, e.g. `UserState.time`
+    pub fn sync_server_time(&self, server_time: u64) -> Result<()> {
+        let now = now_millis()?;
+
+        self.set_time_offset(server_time as i64 - now as i64);
+
+        Ok(())
+    }
+
+    /// Last nonce handed out for `signer`, if any
+    pub fn last_nonce(&self, signer: Address) -> Option<u64> {
+        let last = self.last.read().unwrap_or_else(|e| e.into_inner());
+
+        last.get(&signer).map(|nonce| nonce.load(Ordering::SeqCst))
+    }
+
+    fn now(&self) -> Result<u64> {
+        let now = now_millis()? as i64 + self.time_offset();
+
+        Ok(now.max(0) as u64)
+    }
+}
+
+impl NonceProvider for NonceManager {
+    fn next_nonce(&self, signer: Address) -> Result<u64> {
+        let now = self.now()?;
+
+        let bump = |last: &AtomicU64| {
+            let prev = last
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+                    Some(now.max(last + 1))
+                })
+                .expect("closure always returns Some");
+
+            now.max(prev + 1)
+        };
+
+        {
+            let last = self.last.read().unwrap_or_else(|e| e.into_inner());
+
+            if let Some(last) = last.get(&signer) {
+                return Ok(bump(last));
+            }
+        }
+
+        let mut last = self.last.write().unwrap_or_else(|e| e.into_inner());
+
+        Ok(bump(last.entry(signer).or_default()))
+    }
+}
+
+fn now_millis() -> Result<u64> {
+    let now = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH)?;
+
+    Ok(now.as_millis() as u64)
+}
@@ -0,0 +1,288 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use ethers::types::Address;
+
+use crate::{
+    error::Result,
+    types::{
+        exchange::request::{Action, Request as ExchangeRequest},
+        info::request::Request as InfoRequest,
+    },
+    Error,
+};
+
+/// Weight budget Hyperliquid grants per IP address and per minute
+pub const DEFAULT_WEIGHT_PER_MINUTE: u32 = 1200;
+
+/// Actions an address can send before its trading volume raises the limit
+pub const DEFAULT_ACTIONS_PER_ADDRESS: u64 = 10_000;
+
+/// Delay Hyperliquid enforces between two requests of an address which used all its actions
+pub const THROTTLED_ACTION_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Weight of a request against the per-IP rate limit of Hyperliquid, depending on the
+/// endpoint (info or exchange) and on the kind of request.
+///
+/// Weights follow the exchange documentation: exchange actions weigh `1 + floor(batch / 40)`,
+/// info requests weigh 2 or 20 depending on how expensive they are to serve.
+pub trait Weighted {
+    fn weight(&self) -> u32;
+
+    /// Actions counted against the per-address limit, a batch counting one per order or cancel
+    fn actions(&self) -> u32 {
+        0
+    }
+}
+
+impl Weighted for InfoRequest {
+    fn weight(&self) -> u32 {
+        match self {
+            InfoRequest::L2Book { .. }
+            | InfoRequest::AllMids
+            | InfoRequest::ClearinghouseState { .. }
+            | InfoRequest::OrderStatus { .. }
+            | InfoRequest::SpotClearinghouseState { .. } => 2,
+            _ => 20,
+        }
+    }
+}
+
+impl Weighted for ExchangeRequest {
+    fn weight(&self) -> u32 {
+        1 + self.actions() / 40
+    }
+
+    fn actions(&self) -> u32 {
+        let batch = match &self.action {
+            Action::Order { orders, .. } => orders.len(),
+            Action::Cancel { cancels } => cancels.len(),
+            Action::CancelByCloid { cancels } => cancels.len(),
+            Action::BatchModify { modifies, .. } => modifies.len(),
+            _ => 1,
+        };
+
+        batch.max(1) as u32
+    }
+}
+
+/// What a [`RateLimiter`] does with a request that would exceed the remaining budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Wait until enough capacity was refilled
+    Queue,
+    /// Fail with [`Error::RateLimitExceeded`] or [`Error::AddressRateLimitExceeded`]
+    Reject,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    available: f64,
+    updated_at: Instant,
+}
+
+#[derive(Debug)]
+struct AddressBudget {
+    used: u64,
+    cap: u64,
+    throttled_at: Option<Instant>,
+}
+
+impl Default for AddressBudget {
+    fn default() -> Self {
+        Self {
+            used: 0,
+            cap: DEFAULT_ACTIONS_PER_ADDRESS,
+            throttled_at: None,
+        }
+    }
+}
+
+/// Client-side limiter keeping requests within the weight budget of Hyperliquid.
+///
+/// The budget is a token bucket of `capacity` weight, refilled continuously over `window`.
+/// A limiter is meant to be shared, e.g. by setting the same `Arc<RateLimiter>` on the
+/// [`Config`](crate::Config) used to build both `Info` and `Exchange`, since the budget
+/// applies to all requests sent from the same IP address.
+///
+/// Actions are also counted per address, see [`RateLimiter::acquire_actions`]. Hyperliquid
+/// raises the limit of an address with its trading volume, so the count starts at
+/// [`DEFAULT_ACTIONS_PER_ADDRESS`] until synced with [`RateLimiter::set_address_limit`].
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: u32,
+    window: Duration,
+    mode: RateLimitMode,
+    bucket: Mutex<Bucket>,
+    throttled_interval: Duration,
+    addresses: Mutex<HashMap<Address, AddressBudget>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_WEIGHT_PER_MINUTE,
+            Duration::from_secs(60),
+            RateLimitMode::Queue,
+        )
+    }
+}
+
+impl RateLimiter {
+    /// # Arguments
+    /// * `capacity` - The weight that can be spent within `window`
+    /// * `window` - The time it takes to refill the whole capacity
+    /// * `mode` - Whether to wait or fail when the budget is exhausted
+    pub fn new(capacity: u32, window: Duration, mode: RateLimitMode) -> Self {
+        Self {
+            capacity,
+            window,
+            mode,
+            bucket: Mutex::new(Bucket {
+                available: capacity as f64,
+                updated_at: Instant::now(),
+            }),
+            throttled_interval: THROTTLED_ACTION_INTERVAL,
+            addresses: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Delay between two requests of an address which used all its actions, defaults to
+    /// [`THROTTLED_ACTION_INTERVAL`]
+    pub fn set_throttled_interval(&mut self, throttled_interval: Duration) {
+        self.throttled_interval = throttled_interval;
+    }
+
+    /// Weight that can be spent within one window
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    /// Weight that can currently be spent without waiting
+    pub fn remaining(&self) -> u32 {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+
+        self.refill(&mut bucket);
+
+        bucket.available as u32
+    }
+
+    /// Spend `weight` from the budget, waiting for it to refill or failing depending on the mode.
+    /// A request weighing more than the whole capacity fails with
+    /// [`Error::WeightExceedsCapacity`] in both modes.
+    pub async fn acquire(&self, weight: u32) -> Result<()> {
+        if weight > self.capacity {
+            return Err(Error::WeightExceedsCapacity {
+                weight,
+                capacity: self.capacity,
+            });
+        }
+
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+
+                self.refill(&mut bucket);
+
+                let missing = weight as f64 - bucket.available;
+
+                if missing <= 0.0 {
+                    bucket.available -= weight as f64;
+                    return Ok(());
+                }
+
+                if self.mode == RateLimitMode::Reject {
+                    return Err(Error::RateLimitExceeded {
+                        weight,
+                        remaining: bucket.available as u32,
+                    });
+                }
+
+                self.window
+                    .mul_f64(missing / self.capacity as f64)
+                    .max(Duration::from_millis(1))
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Sync the action count of `address` with the exchange, e.g. with the `n_requests_used`
+    /// and `n_requests_cap` of [`Info::user_rate_limit`](crate::Info::user_rate_limit)
+    pub fn set_address_limit(&self, address: Address, used: u64, cap: u64) {
+        let mut addresses = self.addresses.lock().unwrap_or_else(|e| e.into_inner());
+        let budget = addresses.entry(address).or_default();
+
+        budget.used = used;
+        budget.cap = cap;
+    }
+
+    /// Actions `address` can still send before being throttled
+    pub fn remaining_actions(&self, address: Address) -> u64 {
+        let addresses = self.addresses.lock().unwrap_or_else(|e| e.into_inner());
+
+        addresses
+            .get(&address)
+            .map_or(DEFAULT_ACTIONS_PER_ADDRESS, |budget| {
+                budget.cap.saturating_sub(budget.used)
+            })
+    }
+
+    /// Count `actions` sent by `address`. Once all its actions are used, the address is
+    /// throttled to one request per throttled interval, waited for or failing with
+    /// [`Error::AddressRateLimitExceeded`] depending on the mode.
+    pub async fn acquire_actions(&self, address: Address, actions: u32) -> Result<()> {
+        loop {
+            let wait = {
+                let mut addresses = self.addresses.lock().unwrap_or_else(|e| e.into_inner());
+                let budget = addresses.entry(address).or_default();
+
+                let wait = if budget.used + actions as u64 <= budget.cap {
+                    Duration::ZERO
+                } else {
+                    budget.throttled_at.map_or(Duration::ZERO, |throttled_at| {
+                        self.throttled_interval
+                            .saturating_sub(throttled_at.elapsed())
+                    })
+                };
+
+                if wait.is_zero() {
+                    if budget.used + actions as u64 > budget.cap {
+                        budget.throttled_at = Some(Instant::now());
+                    }
+                    budget.used += actions as u64;
+                    return Ok(());
+                }
+
+                if self.mode == RateLimitMode::Reject {
+                    return Err(Error::AddressRateLimitExceeded {
+                        address,
+                        used: budget.used,
+                        cap: budget.cap,
+                    });
+                }
+
+                wait
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated_at);
+
+        bucket.available = (bucket.available
+            + self.capacity as f64 * elapsed.as_secs_f64() / self.window.as_secs_f64())
+        .min(self.capacity as f64);
+        bucket.updated_at = now;
+    }
+}
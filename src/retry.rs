@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use crate::types::{exchange::request::Action, Cloid};
+
+/// How [`Exchange::submit`](crate::Exchange::submit) retries requests failing with a
+/// transient error (see [`Error::is_transient`](crate::Error::is_transient)).
+///
+/// The signed request is resubmitted as is, with the same nonce, and only when it can't be
+/// executed twice, see [`resubmission`].
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of resubmissions after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first resubmission
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between two attempts
+    pub max_backoff: Duration,
+    /// Factor the delay grows by after each attempt
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the resubmission number `retry`, starting at 0
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .mul_f64(self.multiplier.powi(retry as i32))
+            .min(self.max_backoff)
+    }
+}
+
+/// Whether an action which may have reached the exchange can be submitted again
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resubmission {
+    /// Submitting the action twice has the same effect as submitting it once, e.g. a cancel
+    Always,
+    /// Orders can be resubmitted once none of their client order ids is known by the exchange
+    IfOrdersUnknown(Vec<Cloid>),
+    /// The action, e.g. a `UsdSend` or an order without a cloid, must not be submitted twice
+    Never,
+}
+
+/// Resubmission strategy of `action`, applied when a submission failed without a response
+/// telling whether the action was executed
+pub fn resubmission(action: &Action) -> Resubmission {
+    match action {
+        Action::Order { orders, .. } => orders
+            .iter()
+            .map(|order| order.cloid)
+            .collect::<Option<Vec<_>>>()
+            .map_or(Resubmission::Never, Resubmission::IfOrdersUnknown),
+        Action::Cancel { .. }
+        | Action::CancelByCloid { .. }
+        | Action::TwapCancel { .. }
+        | Action::UpdateLeverage { .. }
+        | Action::ScheduleCancel { .. }
+        | Action::SubAccountModify { .. }
+        | Action::ApproveBuilderFee(_) => Resubmission::Always,
+        _ => Resubmission::Never,
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use serde_json::Value;
+
+use crate::{config::Config, error::Result, types::API, Error};
+
+/// Raw response of the API, decoded and checked by the client
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests to the API on behalf of `Info` and `Exchange`.
+///
+/// [`ReqwestTransport`] is used by default, [`MemoryTransport`] can be set on the
+/// [`Config`] to test code using this crate offline.
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Send `payload` to `endpoint`
+    ///
+    /// # Arguments
+    /// * `endpoint` - The endpoint to send the request to
+    /// * `payload` - The JSON body of the request, e.g. `{"type": "allMids"}`
+    async fn post(&self, endpoint: &API, payload: &Value) -> Result<TransportResponse>;
+}
+
+/// HTTP transport sending requests to the REST endpoint of a [`Config`]
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    inner_client: reqwest::Client,
+    host: String,
+    headers: HeaderMap,
+}
+
+impl ReqwestTransport {
+    /// Fails if the http client can't be built from `config`, see [`Config::http_client`]
+    pub fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            inner_client: config.http_client()?,
+            host: config.rest_endpoint.clone(),
+            headers: config.headers.clone(),
+        })
+    }
+
+    fn build_headers(&self) -> HeaderMap {
+        let mut headers = self.headers.clone();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn post(&self, endpoint: &API, payload: &Value) -> Result<TransportResponse> {
+        let url = &format!("{}{}", self.host, String::from(endpoint));
+
+        let response = self
+            .inner_client
+            .post(url)
+            .headers(self.build_headers())
+            .json(payload)
+            .send()
+            .await?;
+
+        Ok(TransportResponse {
+            status: response.status().as_u16(),
+            body: response.text().await?,
+        })
+    }
+}
+
+/// Transport of a client whose http client couldn't be built, failing every request with
+/// the build error instead of panicking when the client is created
+#[derive(Debug, Clone)]
+pub(crate) struct FailedTransport(pub(crate) String);
+
+#[async_trait]
+impl Transport for FailedTransport {
+    async fn post(&self, _endpoint: &API, _payload: &Value) -> Result<TransportResponse> {
+        Err(Error::HttpClient(self.0.clone()))
+    }
+}
+
+/// In-memory transport answering with canned responses and recording every payload sent.
+///
+/// Responses are looked up by endpoint and request type, i.e. the `type` of an info request
+/// (e.g. `l2Book`) or of the action of an exchange request (e.g. `order`). Clones share
+/// their responses and recorded payloads.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTransport {
+    responses: Arc<Mutex<HashMap<(API, String), TransportResponse>>>,
+    sent: Arc<Mutex<Vec<(API, Value)>>>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests of `request_type` sent to `endpoint` with `response`
+    ///
+    /// # Arguments
+    /// * `endpoint` - The endpoint of the request
+    /// * `request_type` - The type of the request, e.g. `allMids` or `order`
+    /// * `response` - The JSON response
+    pub fn respond(&self, endpoint: API, request_type: &str, response: Value) {
+        self.respond_with(endpoint, request_type, 200, response.to_string());
+    }
+
+    /// Same as [`MemoryTransport::respond`], with a raw status code and body, e.g. to simulate
+    /// a rate limited request
+    pub fn respond_with(&self, endpoint: API, request_type: &str, status: u16, body: String) {
+        self.lock_responses().insert(
+            (endpoint, request_type.to_string()),
+            TransportResponse { status, body },
+        );
+    }
+
+    /// Every payload sent so far, in order
+    pub fn sent(&self) -> Vec<(API, Value)> {
+        self.sent.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Payloads of `request_type` sent to `endpoint` so far, in order
+    pub fn sent_of(&self, endpoint: API, request_type: &str) -> Vec<Value> {
+        self.sent()
+            .into_iter()
+            .filter(|(sent_to, payload)| {
+                *sent_to == endpoint && request_type_of(endpoint, payload) == Some(request_type)
+            })
+            .map(|(_, payload)| payload)
+            .collect()
+    }
+
+    /// Forget the payloads sent so far
+    pub fn clear_sent(&self) {
+        self.sent.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn lock_responses(&self) -> MutexGuard<'_, HashMap<(API, String), TransportResponse>> {
+        self.responses.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn post(&self, endpoint: &API, payload: &Value) -> Result<TransportResponse> {
+        self.sent
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((*endpoint, payload.clone()));
+
+        let request_type = request_type_of(*endpoint, payload).unwrap_or_default();
+
+        self.lock_responses()
+            .get(&(*endpoint, request_type.to_string()))
+            .cloned()
+            .ok_or_else(|| {
+                Error::Http(
+                    404,
+                    format!("No response for {endpoint:?} request {request_type}"),
+                )
+            })
+    }
+}
+
+fn request_type_of(endpoint: API, payload: &Value) -> Option<&str> {
+    match endpoint {
+        API::Info => payload["type"].as_str(),
+        API::Exchange => payload["action"]["type"].as_str(),
+    }
+}
@@ -31,7 +31,7 @@ impl std::fmt::Display for Chain {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
 pub enum HyperliquidChain {
     Mainnet,
@@ -51,6 +51,7 @@ impl std::fmt::Display for HyperliquidChain {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum API {
     Info,
     Exchange,
@@ -58,6 +59,11 @@ pub enum API {
 
 pub type Cloid = Uuid;
 
+/// Numeric value such as a price, size or balance, in the wire format of the API.
+/// With the `decimal` feature, converted to and from `rust_decimal::Decimal` by
+/// [`ToDecimal`](crate::ToDecimal) and [`from_decimal`](crate::from_decimal)
+pub type Number = String;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Oid {
@@ -66,7 +72,7 @@ pub enum Oid {
     Cloid(Cloid),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Side {
     B,
@@ -79,6 +85,8 @@ pub mod agent {
             types::H256,
         };
 
+        use crate::types::HyperliquidChain;
+
         #[derive(Eip712, Clone, EthAbiType)]
         #[eip712(
             name = "Exchange",
@@ -90,6 +98,14 @@ pub mod agent {
             pub source: String,
             pub connection_id: H256,
         }
+
+        /// Source of the agent signing L1 actions on `chain`
+        pub fn source(chain: HyperliquidChain) -> String {
+            match chain {
+                HyperliquidChain::Mainnet => "a".to_string(),
+                HyperliquidChain::Testnet => "b".to_string(),
+            }
+        }
     }
 }
 
@@ -174,14 +190,59 @@ pub mod info {
             SpotClearinghouseState {
                 user: Address,
             },
+
+            #[serde(rename_all = "camelCase")]
+            VaultDetails {
+                vault_address: Address,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                user: Option<Address>,
+            },
+
+            UserVaultEquities {
+                user: Address,
+            },
+
+            UserTwapSliceFills {
+                user: Address,
+            },
+
+            TwapHistory {
+                user: Address,
+            },
+
+            Delegations {
+                user: Address,
+            },
+
+            MaxBuilderFee {
+                user: Address,
+                builder: Address,
+            },
+
+            DelegatorSummary {
+                user: Address,
+            },
+
+            DelegatorHistory {
+                user: Address,
+            },
+
+            DelegatorRewards {
+                user: Address,
+            },
+
+            UserRateLimit {
+                user: Address,
+            },
         }
     }
 
     pub mod response {
         use ethers::types::Address;
         use serde::{Deserialize, Serialize};
+        use serde_json::Value;
 
-        use crate::types::Side;
+        use crate::types::{Number, Side};
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
@@ -208,14 +269,14 @@ pub mod info {
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct Ctx {
-            pub funding: String,
-            pub open_interest: String,
-            pub prev_day_px: String,
-            pub day_ntl_vlm: String,
-            pub premium: Option<String>,
-            pub oracle_px: String,
-            pub mark_px: String,
-            pub mid_px: Option<String>,
+            pub funding: Number,
+            pub open_interest: Number,
+            pub prev_day_px: Number,
+            pub day_ntl_vlm: Number,
+            pub premium: Option<Number>,
+            pub oracle_px: Number,
+            pub mark_px: Number,
+            pub mid_px: Option<Number>,
             pub impact_pxs: Option<ImpactPx>,
         }
 
@@ -229,9 +290,9 @@ pub mod info {
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct CumFunding {
-            pub all_time: String,
-            pub since_change: String,
-            pub since_open: String,
+            pub all_time: Number,
+            pub since_change: Number,
+            pub since_open: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -243,19 +304,18 @@ pub mod info {
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
-
         pub struct Position {
             pub coin: String,
             pub cum_funding: CumFunding,
-            pub entry_px: Option<String>,
+            pub entry_px: Option<Number>,
             pub leverage: Leverage,
-            pub liquidation_px: Option<String>,
-            pub margin_used: String,
+            pub liquidation_px: Option<Number>,
+            pub margin_used: Number,
             pub max_leverage: u32,
-            pub position_value: String,
-            pub return_on_equity: String,
-            pub szi: String,
-            pub unrealized_pnl: String,
+            pub position_value: Number,
+            pub return_on_equity: Number,
+            pub szi: Number,
+            pub unrealized_pnl: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -269,10 +329,10 @@ pub mod info {
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct MarginSummary {
-            pub account_value: String,
-            pub total_margin_used: String,
-            pub total_ntl_pos: String,
-            pub total_raw_usd: String,
+            pub account_value: Number,
+            pub total_margin_used: Number,
+            pub total_ntl_pos: Number,
+            pub total_raw_usd: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -281,19 +341,19 @@ pub mod info {
             pub asset_positions: Vec<AssetPosition>,
             pub margin_summary: MarginSummary,
             pub cross_margin_summary: MarginSummary,
-            pub withdrawable: String,
+            pub withdrawable: Number,
             pub time: u64,
-            pub cross_maintenance_margin_used: String,
+            pub cross_maintenance_margin_used: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct OpenOrder {
             pub coin: String,
-            pub limit_px: String,
+            pub limit_px: Number,
             pub oid: u64,
             pub side: Side,
-            pub sz: String,
+            pub sz: Number,
             pub timestamp: u64,
         }
 
@@ -303,44 +363,45 @@ pub mod info {
             pub coin: String,
             pub is_position_tpsl: bool,
             pub is_trigger: bool,
-            pub limit_px: String,
+            pub limit_px: Number,
             pub oid: u64,
             pub order_type: String,
-            pub orig_sz: String,
+            pub orig_sz: Number,
             pub reduce_only: bool,
             pub side: Side,
-            pub sz: String,
+            pub sz: Number,
             pub timestamp: u64,
             pub trigger_condition: String,
-            pub trigger_px: String,
+            pub trigger_px: Number,
+            pub cloid: Option<String>,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct UserFill {
             pub coin: String,
-            pub px: String,
-            pub sz: String,
+            pub px: Number,
+            pub sz: Number,
             pub side: Side,
             pub time: u64,
-            pub start_position: String,
+            pub start_position: Number,
             pub dir: String,
-            pub closed_pnl: String,
+            pub closed_pnl: Number,
             pub hash: String,
             pub oid: u64,
             pub crossed: bool,
-            pub fee: String,
+            pub fee: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct Delta {
             pub coin: String,
-            pub funding_rate: String,
-            pub szi: String,
+            pub funding_rate: Number,
+            pub szi: Number,
             #[serde(rename = "type")]
             pub type_: String,
-            pub usdc: String,
+            pub usdc: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -355,16 +416,16 @@ pub mod info {
         #[serde(rename_all = "camelCase")]
         pub struct FundingHistory {
             pub coin: String,
-            pub funding_rate: String,
-            pub premium: String,
+            pub funding_rate: Number,
+            pub premium: Number,
             pub time: u64,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct Level {
-            pub px: String,
-            pub sz: String,
+            pub px: Number,
+            pub sz: Number,
             pub n: u64,
         }
 
@@ -380,8 +441,8 @@ pub mod info {
         pub struct RecentTrades {
             pub coin: String,
             pub side: Side,
-            pub px: String,
-            pub sz: String,
+            pub px: Number,
+            pub sz: Number,
             pub hash: String,
             pub time: u64,
         }
@@ -391,15 +452,15 @@ pub mod info {
         pub struct CandleSnapshot {
             #[serde(rename = "T")]
             pub t_: u64,
-            pub c: String,
-            pub h: String,
+            pub c: Number,
+            pub h: Number,
             pub i: String,
-            pub l: String,
+            pub l: Number,
             pub n: u64,
-            pub o: String,
+            pub o: Number,
             pub s: String,
             pub t: u64,
-            pub v: String,
+            pub v: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -410,17 +471,17 @@ pub mod info {
             pub coin: String,
             pub is_position_tpsl: bool,
             pub is_trigger: bool,
-            pub limit_px: String,
+            pub limit_px: Number,
             pub oid: i64,
             pub order_type: String,
-            pub orig_sz: String,
+            pub orig_sz: Number,
             pub reduce_only: bool,
             pub side: String,
-            pub sz: String,
+            pub sz: Number,
             pub tif: Option<String>,
             pub timestamp: i64,
             pub trigger_condition: String,
-            pub trigger_px: String,
+            pub trigger_px: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -477,12 +538,12 @@ pub mod info {
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct SpotCtx {
-            pub circulating_supply: String,
+            pub circulating_supply: Number,
             pub coin: String,
-            pub day_ntl_vlm: String,
-            pub mark_px: String,
-            pub mid_px: Option<String>,
-            pub prev_day_px: String,
+            pub day_ntl_vlm: Number,
+            pub mark_px: Number,
+            pub mid_px: Option<Number>,
+            pub prev_day_px: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -495,8 +556,8 @@ pub mod info {
         #[derive(Debug, Serialize, Deserialize)]
         pub struct Balance {
             pub coin: String,
-            pub hold: String,
-            pub total: String,
+            pub hold: Number,
+            pub total: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -504,6 +565,154 @@ pub mod info {
         pub struct UserSpotState {
             pub balances: Vec<Balance>,
         }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct VaultFollower {
+            /// Follower address, or `Leader` for the vault leader
+            pub user: String,
+            pub vault_equity: Number,
+            pub pnl: Number,
+            pub all_time_pnl: Number,
+            pub days_following: u64,
+            pub vault_entry_time: u64,
+            pub lockup_until: u64,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct VaultDetails {
+            pub name: String,
+            pub vault_address: Address,
+            pub leader: Address,
+            pub description: String,
+            pub portfolio: Value,
+            pub apr: f64,
+            pub follower_state: Option<VaultFollower>,
+            pub leader_fraction: f64,
+            pub leader_commission: f64,
+            pub followers: Vec<VaultFollower>,
+            pub max_distributable: f64,
+            pub max_withdrawable: f64,
+            pub is_closed: bool,
+            #[serde(default)]
+            pub allow_deposits: bool,
+            #[serde(default)]
+            pub always_close_on_withdraw: bool,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct UserVaultEquity {
+            pub vault_address: Address,
+            pub equity: Number,
+            pub locked_until_timestamp: Option<u64>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct TwapState {
+            pub coin: String,
+            pub user: Address,
+            pub side: Side,
+            pub sz: Number,
+            pub executed_sz: Number,
+            pub executed_ntl: Number,
+            pub minutes: u64,
+            pub reduce_only: bool,
+            pub randomize: bool,
+            pub timestamp: u64,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase", tag = "status")]
+        pub enum TwapStatus {
+            Activated,
+            Finished,
+            Terminated,
+            Error { description: String },
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct TwapHistory {
+            pub time: u64,
+            pub state: TwapState,
+            pub status: TwapStatus,
+            pub twap_id: Option<u64>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct TwapSliceFill {
+            pub fill: UserFill,
+            pub twap_id: u64,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct Delegation {
+            pub validator: Address,
+            pub amount: Number,
+            pub locked_until_timestamp: u64,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct DelegatorSummary {
+            pub delegated: Number,
+            pub undelegated: Number,
+            pub total_pending_withdrawal: Number,
+            pub n_pending_withdrawals: u64,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub enum DelegatorDelta {
+            #[serde(rename_all = "camelCase")]
+            Delegate {
+                validator: Address,
+                amount: Number,
+                is_undelegate: bool,
+            },
+            CDeposit {
+                amount: Number,
+            },
+            Withdrawal {
+                amount: Number,
+                phase: String,
+            },
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct DelegatorHistory {
+            pub time: u64,
+            pub hash: String,
+            pub delta: DelegatorDelta,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub enum RewardSource {
+            Delegation,
+            Commission,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct DelegatorReward {
+            pub time: u64,
+            pub source: RewardSource,
+            pub total_amount: Number,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct UserRateLimit {
+            pub cum_vlm: Number,
+            pub n_requests_used: u64,
+            pub n_requests_cap: u64,
+        }
     }
 }
 
@@ -523,12 +732,15 @@ pub mod exchange {
         use serde::{Deserialize, Serialize};
 
         use crate::{
-            types::{Cloid, HyperliquidChain},
+            types::{
+                agent::l1, info::response::FrontendOpenOrders, Cloid, HyperliquidChain, Number,
+                Side,
+            },
             utils::{as_hex, as_hex_option},
             Error, Result,
         };
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "PascalCase")]
         pub enum Tif {
             Gtc,
@@ -537,35 +749,35 @@ pub mod exchange {
             FrontendMarket,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct Limit {
             pub tif: Tif,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "lowercase")]
         pub enum TpSl {
             Tp,
             Sl,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct Trigger {
             pub is_market: bool,
-            pub trigger_px: String,
+            pub trigger_px: Number,
             pub tpsl: TpSl,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub enum OrderType {
             Limit(Limit),
             Trigger(Trigger),
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct OrderRequest {
             #[serde(rename = "a", alias = "asset")]
@@ -573,9 +785,9 @@ pub mod exchange {
             #[serde(rename = "b", alias = "isBuy")]
             pub is_buy: bool,
             #[serde(rename = "p", alias = "limitPx")]
-            pub limit_px: String,
+            pub limit_px: Number,
             #[serde(rename = "s", alias = "sz")]
-            pub sz: String,
+            pub sz: Number,
             #[serde(rename = "r", alias = "reduceOnly", default)]
             pub reduce_only: bool,
             #[serde(rename = "t", alias = "orderType")]
@@ -589,14 +801,25 @@ pub mod exchange {
             pub cloid: Option<Cloid>,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub enum Grouping {
             Na,
             NormalTpsl,
+            PositionTpsl,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        /// Builder attributed to an order, which receives `fee` on top of the exchange fees
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        pub struct Builder {
+            #[serde(rename = "b", alias = "builder")]
+            pub builder: Address,
+            /// Fee in tenths of a basis point, e.g. `10` for 1 basis point
+            #[serde(rename = "f", alias = "fee")]
+            pub fee: u64,
+        }
+
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct CancelRequest {
             #[serde(rename = "a", alias = "asset")]
@@ -605,7 +828,7 @@ pub mod exchange {
             pub oid: u64,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct CancelByCloidRequest {
             pub asset: u32,
@@ -613,14 +836,41 @@ pub mod exchange {
             pub cloid: Cloid,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        /// Selects which open orders to cancel, every unset field matches all orders
+        #[derive(Debug, Default, Clone)]
+        pub struct CancelFilter {
+            pub coin: Option<String>,
+            pub side: Option<Side>,
+            pub reduce_only: Option<bool>,
+            /// Hex prefix of the client order id, e.g. `0x1234`
+            pub cloid_prefix: Option<String>,
+        }
+
+        impl CancelFilter {
+            /// Returns `true` if the open order should be cancelled
+            pub fn matches(&self, order: &FrontendOpenOrders) -> bool {
+                let matches_cloid = match &self.cloid_prefix {
+                    Some(prefix) => order.cloid.as_ref().is_some_and(|cloid| {
+                        cloid.to_lowercase().starts_with(&prefix.to_lowercase())
+                    }),
+                    None => true,
+                };
+
+                (self.coin.is_none() || self.coin.as_ref() == Some(&order.coin))
+                    && (self.side.is_none() || self.side == Some(order.side))
+                    && (self.reduce_only.is_none() || self.reduce_only == Some(order.reduce_only))
+                    && matches_cloid
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct ModifyRequest {
             pub oid: u64,
             pub order: OrderRequest,
         }
 
-        #[derive(Debug, Serialize, Deserialize)]
+        #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(rename_all = "camelCase")]
         pub struct TwapRequest {
             #[serde(rename = "a", alias = "asset")]
@@ -628,7 +878,7 @@ pub mod exchange {
             #[serde(rename = "b", alias = "isBuy")]
             pub is_buy: bool,
             #[serde(rename = "s", alias = "sz")]
-            pub sz: String,
+            pub sz: Number,
             #[serde(rename = "r", alias = "reduceOnly", default)]
             pub reduce_only: bool,
             /// Running Time (5m - 24h)
@@ -640,6 +890,149 @@ pub mod exchange {
             pub randomize: bool,
         }
 
+        /// Payload of an action the user signs directly with EIP-712, rather than through an agent
+        pub trait UserSignedAction {
+            /// EIP-712 primary type without the `HyperliquidTransaction:` prefix, e.g. `UsdSend`
+            const PRIMARY_TYPE: &'static str;
+
+            fn signature_chain_id(&self) -> U256;
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain;
+
+            /// Signed fields following `hyperliquidChain`
+            fn params() -> Vec<(String, ParamType)>;
+
+            /// Values of the fields in [`UserSignedAction::params`]
+            fn tokens(&self) -> Vec<Token>;
+
+            /// Nonce carried by the action, which must also be used as the request nonce
+            fn nonce(&self) -> u64;
+        }
+
+        /// Object safe view of a [`UserSignedAction`], see [`Action::as_user_signed`]
+        pub trait UserSigned {
+            fn nonce(&self) -> u64;
+
+            /// Typed data the user signs
+            fn typed_data(&self) -> Result<TypedData>;
+
+            /// Typed data each authorized user of `multi_sig_user` signs, see [`MultiSigEnvelope`]
+            fn multi_sig_typed_data(
+                &self,
+                multi_sig_user: Address,
+                outer_signer: Address,
+            ) -> Result<TypedData>;
+        }
+
+        impl<T: UserSignedAction + Eip712> UserSigned for T {
+            fn nonce(&self) -> u64 {
+                UserSignedAction::nonce(self)
+            }
+
+            fn typed_data(&self) -> Result<TypedData> {
+                TypedData::new(self)
+            }
+
+            fn multi_sig_typed_data(
+                &self,
+                multi_sig_user: Address,
+                outer_signer: Address,
+            ) -> Result<TypedData> {
+                TypedData::new(&MultiSigEnvelope {
+                    payload: self,
+                    multi_sig_user,
+                    outer_signer,
+                })
+            }
+        }
+
+        /// EIP-712 payload with its domain and struct hash already computed, so actions only
+        /// known at runtime can be signed by any [`Signer`](ethers::signers::Signer)
+        #[derive(Debug, Clone)]
+        pub struct TypedData {
+            domain: EIP712Domain,
+            struct_hash: [u8; 32],
+        }
+
+        impl TypedData {
+            pub fn new<T: Eip712>(payload: &T) -> Result<Self> {
+                let map_err = |e: T::Error| Error::SignatureError(e.to_string());
+
+                Ok(Self {
+                    domain: payload.domain().map_err(map_err)?,
+                    struct_hash: payload.struct_hash().map_err(map_err)?,
+                })
+            }
+
+            /// EIP-712 digest of the payload
+            pub fn signing_hash(&self) -> Result<H256> {
+                let digest = self
+                    .encode_eip712()
+                    .map_err(|e| Error::SignatureError(e.to_string()))?;
+
+                Ok(digest.into())
+            }
+        }
+
+        impl Eip712 for TypedData {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(self.domain.clone())
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Err(Eip712Error::Message(
+                    "the type hash of pre-hashed typed data is unknown".to_string(),
+                ))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(self.struct_hash)
+            }
+        }
+
+        fn user_signed_domain<T: UserSignedAction>(payload: &T) -> EIP712Domain {
+            EIP712Domain {
+                name: Some("HyperliquidSignTransaction".into()),
+                version: Some("1".into()),
+                chain_id: Some(payload.signature_chain_id()),
+                verifying_contract: Some(Address::zero()),
+                salt: None,
+            }
+        }
+
+        /// `extra` fields are signed right after `hyperliquidChain`
+        fn user_signed_type_hash<T: UserSignedAction>(extra: &[(String, ParamType)]) -> [u8; 32] {
+            let mut params = vec![("hyperliquidChain".to_string(), ParamType::String)];
+            params.extend_from_slice(extra);
+            params.extend(T::params());
+
+            make_type_hash(
+                format!("HyperliquidTransaction:{}", T::PRIMARY_TYPE),
+                &params,
+            )
+        }
+
+        fn user_signed_struct_hash<T: UserSignedAction>(
+            payload: &T,
+            type_hash: [u8; 32],
+            extra: Vec<Token>,
+        ) -> [u8; 32] {
+            let mut tokens = vec![
+                Token::Uint(type_hash.into()),
+                encode_eip712_type(payload.hyperliquid_chain().to_string().into_token()),
+            ];
+            tokens.extend(
+                extra
+                    .into_iter()
+                    .chain(payload.tokens())
+                    .map(encode_eip712_type),
+            );
+
+            keccak256(encode(&tokens))
+        }
+
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
         pub struct Withdraw3 {
@@ -650,39 +1043,51 @@ pub mod exchange {
             pub time: u64,
         }
 
+        impl UserSignedAction for Withdraw3 {
+            const PRIMARY_TYPE: &'static str = "Withdraw";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("destination".to_string(), ParamType::String),
+                    ("amount".to_string(), ParamType::String),
+                    ("time".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.destination.clone().into_token(),
+                    self.amount.clone().into_token(),
+                    self.time.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.time
+            }
+        }
+
         impl Eip712 for Withdraw3 {
             type Error = Eip712Error;
 
             fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
-                Ok(EIP712Domain {
-                    name: Some("HyperliquidSignTransaction".into()),
-                    version: Some("1".into()),
-                    chain_id: Some(self.signature_chain_id),
-                    verifying_contract: Some(Address::zero()),
-                    salt: None,
-                })
+                Ok(user_signed_domain(self))
             }
 
             fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
-                Ok(make_type_hash(
-                    "HyperliquidTransaction:Withdraw".into(),
-                    &[
-                        ("hyperliquidChain".to_string(), ParamType::String),
-                        ("destination".to_string(), ParamType::String),
-                        ("amount".to_string(), ParamType::String),
-                        ("time".to_string(), ParamType::Uint(64)),
-                    ],
-                ))
+                Ok(user_signed_type_hash::<Self>(&[]))
             }
 
             fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
-                Ok(keccak256(encode(&[
-                    Token::Uint(Self::type_hash()?.into()),
-                    encode_eip712_type(self.hyperliquid_chain.to_string().into_token()),
-                    encode_eip712_type(self.destination.clone().into_token()),
-                    encode_eip712_type(self.amount.clone().into_token()),
-                    encode_eip712_type(self.time.into_token()),
-                ])))
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
             }
         }
 
@@ -703,86 +1108,639 @@ pub mod exchange {
             pub time: u64,
         }
 
+        impl UserSignedAction for UsdSend {
+            const PRIMARY_TYPE: &'static str = "UsdSend";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("destination".to_string(), ParamType::String),
+                    ("amount".to_string(), ParamType::String),
+                    ("time".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.destination.clone().into_token(),
+                    self.amount.clone().into_token(),
+                    self.time.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.time
+            }
+        }
+
         impl Eip712 for UsdSend {
             type Error = Eip712Error;
 
             fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
-                Ok(EIP712Domain {
-                    name: Some("HyperliquidSignTransaction".into()),
-                    version: Some("1".into()),
-                    chain_id: Some(self.signature_chain_id),
-                    verifying_contract: Some(Address::zero()),
-                    salt: None,
-                })
+                Ok(user_signed_domain(self))
             }
 
             fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
-                Ok(make_type_hash(
-                    "HyperliquidTransaction:UsdSend".into(),
-                    &[
-                        ("hyperliquidChain".to_string(), ParamType::String),
-                        ("destination".to_string(), ParamType::String),
-                        ("amount".to_string(), ParamType::String),
-                        ("time".to_string(), ParamType::Uint(64)),
-                    ],
-                ))
+                Ok(user_signed_type_hash::<Self>(&[]))
             }
 
             fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
-                Ok(keccak256(encode(&[
-                    Token::Uint(Self::type_hash()?.into()),
-                    encode_eip712_type(self.hyperliquid_chain.to_string().into_token()),
-                    encode_eip712_type(self.destination.clone().into_token()),
-                    encode_eip712_type(self.amount.clone().into_token()),
-                    encode_eip712_type(self.time.into_token()),
-                ])))
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
             }
         }
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase")]
-        pub struct ApproveAgent {
+        pub struct UsdClassTransfer {
             pub signature_chain_id: U256,
             pub hyperliquid_chain: HyperliquidChain,
-            pub agent_address: Address,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            pub agent_name: Option<String>,
+            pub amount: String,
+            /// true to move usdc from spot to perp, false to move it from perp to spot
+            pub to_perp: bool,
             pub nonce: u64,
         }
 
-        impl Eip712 for ApproveAgent {
-            type Error = Eip712Error;
+        impl UserSignedAction for UsdClassTransfer {
+            const PRIMARY_TYPE: &'static str = "UsdClassTransfer";
 
-            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
-                Ok(EIP712Domain {
-                    name: Some("HyperliquidSignTransaction".into()),
-                    version: Some("1".into()),
-                    chain_id: Some(self.signature_chain_id),
-                    verifying_contract: Some(Address::zero()),
-                    salt: None,
-                })
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("amount".to_string(), ParamType::String),
+                    ("toPerp".to_string(), ParamType::Bool),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.amount.clone().into_token(),
+                    self.to_perp.into_token(),
+                    self.nonce.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for UsdClassTransfer {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct CDeposit {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            /// Amount of HYPE in wei, i.e. 1 HYPE = 100_000_000 wei
+            pub wei: u64,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for CDeposit {
+            const PRIMARY_TYPE: &'static str = "CDeposit";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("wei".to_string(), ParamType::Uint(64)),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![self.wei.into_token(), self.nonce.into_token()]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for CDeposit {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct CWithdraw {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            /// Amount of HYPE in wei, i.e. 1 HYPE = 100_000_000 wei
+            pub wei: u64,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for CWithdraw {
+            const PRIMARY_TYPE: &'static str = "CWithdraw";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("wei".to_string(), ParamType::Uint(64)),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![self.wei.into_token(), self.nonce.into_token()]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for CWithdraw {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct TokenDelegate {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            pub validator: Address,
+            /// Amount of HYPE in wei, i.e. 1 HYPE = 100_000_000 wei
+            pub wei: u64,
+            pub is_undelegate: bool,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for TokenDelegate {
+            const PRIMARY_TYPE: &'static str = "TokenDelegate";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("validator".to_string(), ParamType::Address),
+                    ("wei".to_string(), ParamType::Uint(64)),
+                    ("isUndelegate".to_string(), ParamType::Bool),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.validator.into_token(),
+                    self.wei.into_token(),
+                    self.is_undelegate.into_token(),
+                    self.nonce.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for TokenDelegate {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct SpotSend {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            pub destination: String,
+            /// Token in the format `name:tokenId`, e.g. `PURR:0xc1fb593aeffbeb02f85e0308e9956a90`
+            pub token: String,
+            pub amount: String,
+            pub time: u64,
+        }
+
+        impl UserSignedAction for SpotSend {
+            const PRIMARY_TYPE: &'static str = "SpotSend";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("destination".to_string(), ParamType::String),
+                    ("token".to_string(), ParamType::String),
+                    ("amount".to_string(), ParamType::String),
+                    ("time".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.destination.clone().into_token(),
+                    self.token.clone().into_token(),
+                    self.amount.clone().into_token(),
+                    self.time.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.time
+            }
+        }
+
+        impl Eip712 for SpotSend {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct ApproveAgent {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            pub agent_address: Address,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            pub agent_name: Option<String>,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for ApproveAgent {
+            const PRIMARY_TYPE: &'static str = "ApproveAgent";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("agentAddress".to_string(), ParamType::Address),
+                    ("agentName".to_string(), ParamType::String),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.agent_address.into_token(),
+                    self.agent_name.clone().unwrap_or_default().into_token(),
+                    self.nonce.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for ApproveAgent {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct ApproveBuilderFee {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            /// Max fee as a percentage, e.g. `0.01%` for 1 basis point
+            pub max_fee_rate: String,
+            pub builder: Address,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for ApproveBuilderFee {
+            const PRIMARY_TYPE: &'static str = "ApproveBuilderFee";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("maxFeeRate".to_string(), ParamType::String),
+                    ("builder".to_string(), ParamType::Address),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.max_fee_rate.clone().into_token(),
+                    self.builder.into_token(),
+                    self.nonce.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for ApproveBuilderFee {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        /// Signers of a multi-sig user
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        #[serde(rename_all = "camelCase")]
+        pub struct MultiSigSigners {
+            pub authorized_users: Vec<Address>,
+            /// Number of authorized users that must sign an action
+            pub threshold: usize,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct ConvertToMultiSigUser {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            /// JSON encoded [`MultiSigSigners`], or `null` to convert back to a normal user
+            pub signers: String,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for ConvertToMultiSigUser {
+            const PRIMARY_TYPE: &'static str = "ConvertToMultiSigUser";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("signers".to_string(), ParamType::String),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![self.signers.clone().into_token(), self.nonce.into_token()]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for ConvertToMultiSigUser {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
             }
 
             fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
-                Ok(make_type_hash(
-                    "HyperliquidTransaction:ApproveAgent".into(),
-                    &[
-                        ("hyperliquidChain".to_string(), ParamType::String),
-                        ("agentAddress".to_string(), ParamType::Address),
-                        ("agentName".to_string(), ParamType::String),
-                        ("nonce".to_string(), ParamType::Uint(64)),
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
+            }
+        }
+
+        /// User signed action of a multi-sig user, as signed by each of its authorized users
+        pub struct MultiSigEnvelope<'a, T> {
+            pub payload: &'a T,
+            pub multi_sig_user: Address,
+            pub outer_signer: Address,
+        }
+
+        impl<T: UserSignedAction> Eip712 for MultiSigEnvelope<'_, T> {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self.payload))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<T>(&[
+                    ("payloadMultiSigUser".to_string(), ParamType::Address),
+                    ("outerSigner".to_string(), ParamType::Address),
+                ]))
+            }
+
+            fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_struct_hash(
+                    self.payload,
+                    Self::type_hash()?,
+                    vec![
+                        self.multi_sig_user.into_token(),
+                        self.outer_signer.into_token(),
                     ],
                 ))
             }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct MultiSigPayload {
+            pub multi_sig_user: Address,
+            /// Authorized user submitting the action
+            pub outer_signer: Address,
+            pub action: Box<Action>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct MultiSig {
+            pub signature_chain_id: U256,
+            /// Signatures of the authorized users over the inner action
+            pub signatures: Vec<Signature>,
+            pub payload: MultiSigPayload,
+        }
+
+        impl MultiSig {
+            /// Hash of the action the outer signer signs with [`SendMultiSig`]
+            pub fn action_hash(
+                &self,
+                vault_address: Option<Address>,
+                nonce: u64,
+                expires_after: Option<u64>,
+            ) -> Result<H256> {
+                action_hash(self, vault_address, nonce, expires_after)
+            }
+        }
+
+        /// Signed by the outer signer to submit a [`MultiSig`] action
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct SendMultiSig {
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            pub multi_sig_action_hash: H256,
+            pub nonce: u64,
+        }
+
+        impl UserSignedAction for SendMultiSig {
+            const PRIMARY_TYPE: &'static str = "SendMultiSig";
+
+            fn signature_chain_id(&self) -> U256 {
+                self.signature_chain_id
+            }
+
+            fn hyperliquid_chain(&self) -> &HyperliquidChain {
+                &self.hyperliquid_chain
+            }
+
+            fn params() -> Vec<(String, ParamType)> {
+                vec![
+                    ("multiSigActionHash".to_string(), ParamType::FixedBytes(32)),
+                    ("nonce".to_string(), ParamType::Uint(64)),
+                ]
+            }
+
+            fn tokens(&self) -> Vec<Token> {
+                vec![
+                    self.multi_sig_action_hash.into_token(),
+                    self.nonce.into_token(),
+                ]
+            }
+
+            fn nonce(&self) -> u64 {
+                self.nonce
+            }
+        }
+
+        impl Eip712 for SendMultiSig {
+            type Error = Eip712Error;
+
+            fn domain(&self) -> std::result::Result<EIP712Domain, Self::Error> {
+                Ok(user_signed_domain(self))
+            }
+
+            fn type_hash() -> std::result::Result<[u8; 32], Self::Error> {
+                Ok(user_signed_type_hash::<Self>(&[]))
+            }
 
             fn struct_hash(&self) -> std::result::Result<[u8; 32], Self::Error> {
-                Ok(keccak256(encode(&[
-                    Token::Uint(Self::type_hash()?.into()),
-                    encode_eip712_type(self.hyperliquid_chain.to_string().into_token()),
-                    encode_eip712_type(self.agent_address.into_token()),
-                    encode_eip712_type(self.agent_name.clone().unwrap_or_default().into_token()),
-                    encode_eip712_type(self.nonce.into_token()),
-                ])))
+                Ok(user_signed_struct_hash(self, Self::type_hash()?, vec![]))
             }
         }
 
@@ -792,6 +1750,8 @@ pub mod exchange {
             Order {
                 orders: Vec<OrderRequest>,
                 grouping: Grouping,
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                builder: Option<Builder>,
             },
             Cancel {
                 cancels: Vec<CancelRequest>,
@@ -800,16 +1760,39 @@ pub mod exchange {
                 cancels: Vec<CancelByCloidRequest>,
             },
 
-            Modify(ModifyRequest),
+            Modify {
+                oid: u64,
+                order: OrderRequest,
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                builder: Option<Builder>,
+            },
 
             BatchModify {
                 modifies: Vec<ModifyRequest>,
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                builder: Option<Builder>,
             },
             TwapOrder {
                 twap: TwapRequest,
             },
+            TwapCancel {
+                #[serde(rename = "a", alias = "asset")]
+                asset: u32,
+                #[serde(rename = "t", alias = "twapId")]
+                twap_id: u64,
+            },
             UsdSend(UsdSend),
 
+            SpotSend(SpotSend),
+
+            UsdClassTransfer(UsdClassTransfer),
+
+            CDeposit(CDeposit),
+
+            CWithdraw(CWithdraw),
+
+            TokenDelegate(TokenDelegate),
+
             Withdraw3(Withdraw3),
             #[serde(rename_all = "camelCase")]
             UpdateLeverage {
@@ -824,6 +1807,9 @@ pub mod exchange {
                 ntli: i64,
             },
             ApproveAgent(ApproveAgent),
+            ApproveBuilderFee(ApproveBuilderFee),
+            ConvertToMultiSigUser(ConvertToMultiSigUser),
+            MultiSig(MultiSig),
             CreateSubAccount {
                 name: String,
             },
@@ -842,8 +1828,61 @@ pub mod exchange {
                 code: String,
             },
             ScheduleCancel {
-                time: u64,
+                /// Unsets the scheduled cancel if `None`
+                #[serde(skip_serializing_if = "Option::is_none")]
+                time: Option<u64>,
+            },
+            #[serde(rename_all = "camelCase")]
+            VaultTransfer {
+                vault_address: Address,
+                is_deposit: bool,
+                usd: u64,
             },
+            #[serde(rename_all = "camelCase")]
+            CreateVault {
+                name: String,
+                description: String,
+                initial_usd: u64,
+                nonce: u64,
+            },
+            #[serde(rename_all = "camelCase")]
+            VaultDistribute {
+                vault_address: Address,
+                usd: u64,
+            },
+        }
+
+        impl Action {
+            /// Nonce carried by the action itself, which must also be used as the request nonce
+            pub fn nonce(&self) -> Option<u64> {
+                if let Some(payload) = self.as_user_signed() {
+                    return Some(payload.nonce());
+                }
+
+                match self {
+                    Action::MultiSig(multi_sig) => multi_sig.payload.action.nonce(),
+                    Action::CreateVault { nonce, .. } => Some(*nonce),
+                    _ => None,
+                }
+            }
+
+            /// The payload of an action the user signs directly with EIP-712, `None` for L1
+            /// actions signed through an agent and for multi-sig actions
+            pub fn as_user_signed(&self) -> Option<&dyn UserSigned> {
+                match self {
+                    Action::UsdSend(payload) => Some(payload),
+                    Action::SpotSend(payload) => Some(payload),
+                    Action::UsdClassTransfer(payload) => Some(payload),
+                    Action::CDeposit(payload) => Some(payload),
+                    Action::CWithdraw(payload) => Some(payload),
+                    Action::TokenDelegate(payload) => Some(payload),
+                    Action::Withdraw3(payload) => Some(payload),
+                    Action::ApproveAgent(payload) => Some(payload),
+                    Action::ApproveBuilderFee(payload) => Some(payload),
+                    Action::ConvertToMultiSigUser(payload) => Some(payload),
+                    _ => None,
+                }
+            }
         }
 
         impl Action {
@@ -852,20 +1891,174 @@ pub mod exchange {
                 &self,
                 vault_address: Option<Address>,
                 nonce: u64,
+                expires_after: Option<u64>,
             ) -> Result<H256> {
-                let mut encoded = rmp_serde::to_vec_named(self)
-                    .map_err(|e| Error::RmpSerdeError(e.to_string()))?;
+                action_hash(self, vault_address, nonce, expires_after)
+            }
+        }
 
-                encoded.extend((nonce).to_be_bytes());
+        fn action_hash<T: Serialize>(
+            action: &T,
+            vault_address: Option<Address>,
+            nonce: u64,
+            expires_after: Option<u64>,
+        ) -> Result<H256> {
+            let mut encoded =
+                rmp_serde::to_vec_named(action).map_err(|e| Error::RmpSerdeError(e.to_string()))?;
 
-                if let Some(address) = vault_address {
-                    encoded.push(1);
-                    encoded.extend(address.to_fixed_bytes());
-                } else {
-                    encoded.push(0)
+            encoded.extend((nonce).to_be_bytes());
+
+            if let Some(address) = vault_address {
+                encoded.push(1);
+                encoded.extend(address.to_fixed_bytes());
+            } else {
+                encoded.push(0)
+            }
+
+            if let Some(expires_after) = expires_after {
+                encoded.push(0);
+                encoded.extend(expires_after.to_be_bytes());
+            }
+
+            Ok(keccak256(encoded).into())
+        }
+
+        /// Action of a multi-sig user collecting the signatures of its authorized users. The bundle
+        /// can be serialized and passed between signers, e.g. between offline machines, until
+        /// the threshold is reached and the outer signer submits it.
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct MultiSigBundle {
+            /// Chain id of the domain the outer signer signs the multi-sig action with
+            pub signature_chain_id: U256,
+            pub hyperliquid_chain: HyperliquidChain,
+            pub multi_sig_user: Address,
+            pub outer_signer: Address,
+            pub signers: MultiSigSigners,
+            pub action: Action,
+            pub nonce: u64,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub vault_address: Option<Address>,
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub expires_after: Option<u64>,
+            #[serde(default)]
+            pub signatures: Vec<Signature>,
+        }
+
+        impl MultiSigBundle {
+            /// Typed data each authorized user signs
+            pub fn typed_data(&self) -> Result<TypedData> {
+                match self.action.as_user_signed() {
+                    Some(payload) => {
+                        payload.multi_sig_typed_data(self.multi_sig_user, self.outer_signer)
+                    }
+                    None => TypedData::new(&l1::Agent {
+                        source: l1::source(self.hyperliquid_chain),
+                        connection_id: self.connection_id()?,
+                    }),
+                }
+            }
+
+            /// EIP-712 digest each authorized user signs
+            pub fn signing_hash(&self) -> Result<H256> {
+                self.typed_data()?.signing_hash()
+            }
+
+            /// Connection id the authorized users sign through their agent, if the action is an L1 action
+            pub fn connection_id(&self) -> Result<H256> {
+                action_hash(
+                    &(self.multi_sig_user, self.outer_signer, &self.action),
+                    self.vault_address,
+                    self.nonce,
+                    self.expires_after,
+                )
+            }
+
+            /// Add the signature of an authorized user, returning the recovered signer
+            pub fn add_signature(&mut self, signature: Signature) -> Result<Address> {
+                let signer = signature
+                    .recover(self.signing_hash()?)
+                    .map_err(|e| Error::SignatureError(e.to_string()))?;
+
+                if !self.signers.authorized_users.contains(&signer) {
+                    return Err(Error::UnauthorizedSigner(signer));
+                }
+
+                if self.signed_by()?.contains(&signer) {
+                    return Err(Error::SignatureError(format!("{signer:?} already signed")));
+                }
+
+                self.signatures.push(signature);
+
+                Ok(signer)
+            }
+
+            /// Authorized users that signed the bundle so far
+            pub fn signed_by(&self) -> Result<Vec<Address>> {
+                let digest = self.signing_hash()?;
+
+                self.signatures
+                    .iter()
+                    .map(|signature| {
+                        signature
+                            .recover(digest)
+                            .map_err(|e| Error::SignatureError(e.to_string()))
+                    })
+                    .collect()
+            }
+
+            /// Returns `true` once enough distinct authorized users signed the bundle
+            pub fn is_complete(&self) -> bool {
+                self.checked_signers()
+                    .is_ok_and(|signers| signers >= self.signers.threshold)
+            }
+
+            /// The multi-sig action to be signed by the outer signer, with its nonce and vault address
+            pub fn into_action(self) -> Result<(Action, u64, Option<Address>)> {
+                let signers = self.checked_signers()?;
+
+                if signers < self.signers.threshold {
+                    return Err(Error::MultiSigThreshold(signers, self.signers.threshold));
+                }
+
+                let action = Action::MultiSig(MultiSig {
+                    signature_chain_id: self.signature_chain_id,
+                    signatures: self.signatures,
+                    payload: MultiSigPayload {
+                        multi_sig_user: self.multi_sig_user,
+                        outer_signer: self.outer_signer,
+                        action: Box::new(self.action),
+                    },
+                });
+
+                Ok((action, self.nonce, self.vault_address))
+            }
+
+            /// Number of signers, once checked that every signature is from a distinct authorized user
+            fn checked_signers(&self) -> Result<usize> {
+                let mut signed_by = self.signed_by()?;
+
+                if let Some(signer) = signed_by
+                    .iter()
+                    .find(|signer| !self.signers.authorized_users.contains(signer))
+                {
+                    return Err(Error::InvalidSigners(format!(
+                        "{signer:?} is not an authorized user"
+                    )));
+                }
+
+                let signatures = signed_by.len();
+
+                signed_by.sort();
+                signed_by.dedup();
+
+                if signed_by.len() != signatures {
+                    return Err(Error::InvalidSigners(
+                        "an authorized user signed more than once".to_string(),
+                    ));
                 }
 
-                Ok(keccak256(encoded).into())
+                Ok(signatures)
             }
         }
 
@@ -877,6 +2070,78 @@ pub mod exchange {
             pub signature: Signature,
             #[serde(skip_serializing_if = "Option::is_none")]
             pub vault_address: Option<Address>,
+            /// Timestamp in milliseconds after which the exchange rejects the request, only used by
+            /// L1 actions
+            #[serde(default, skip_serializing_if = "Option::is_none")]
+            pub expires_after: Option<u64>,
+        }
+
+        impl Request {
+            /// EIP-712 digest the signature of this request was made over
+            ///
+            /// # Arguments
+            /// * `chain` - The chain the request is meant for, L1 actions are signed differently on mainnet and testnet
+            pub fn signing_hash(&self, chain: HyperliquidChain) -> Result<H256> {
+                if let Some(payload) = self.action.as_user_signed() {
+                    return payload.typed_data()?.signing_hash();
+                }
+
+                let digest = match &self.action {
+                    Action::MultiSig(multi_sig) => SendMultiSig {
+                        signature_chain_id: multi_sig.signature_chain_id,
+                        hyperliquid_chain: chain,
+                        multi_sig_action_hash: multi_sig.action_hash(
+                            self.vault_address,
+                            self.nonce,
+                            self.expires_after,
+                        )?,
+                        nonce: self.nonce,
+                    }
+                    .encode_eip712(),
+                    action => {
+                        let agent = l1::Agent {
+                            source: l1::source(chain),
+                            connection_id: action.connection_id(
+                                self.vault_address,
+                                self.nonce,
+                                self.expires_after,
+                            )?,
+                        };
+
+                        agent.encode_eip712()
+                    }
+                }
+                .map_err(|e| Error::SignatureError(e.to_string()))?;
+
+                Ok(digest.into())
+            }
+
+            /// Recover the address that signed this request
+            ///
+            /// # Arguments
+            /// * `chain` - The chain the request is meant for
+            pub fn recover_signer(&self, chain: HyperliquidChain) -> Result<Address> {
+                let digest = self.signing_hash(chain)?;
+
+                self.signature
+                    .recover(digest)
+                    .map_err(|e| Error::SignatureError(e.to_string()))
+            }
+
+            /// Check that this request was signed by one of `signers`, e.g. the approved agents of an account
+            ///
+            /// # Arguments
+            /// * `chain` - The chain the request is meant for
+            /// * `signers` - The addresses allowed to sign the request
+            pub fn verify(&self, chain: HyperliquidChain, signers: &[Address]) -> Result<Address> {
+                let signer = self.recover_signer(chain)?;
+
+                if signers.contains(&signer) {
+                    Ok(signer)
+                } else {
+                    Err(Error::UnauthorizedSigner(signer))
+                }
+            }
         }
     }
 
@@ -884,6 +2149,14 @@ pub mod exchange {
         use ethers::types::Address;
         use serde::{Deserialize, Serialize};
 
+        use crate::{
+            types::{
+                exchange::request::{CancelRequest, OrderRequest},
+                Cloid, Number,
+            },
+            Error, Result,
+        };
+
         #[derive(Debug, Serialize, Deserialize)]
         pub struct Resting {
             pub oid: u64,
@@ -893,8 +2166,8 @@ pub mod exchange {
         #[serde(rename_all = "camelCase")]
         pub struct Filled {
             pub oid: u64,
-            pub total_sz: String,
-            pub avg_px: String,
+            pub total_sz: Number,
+            pub avg_px: Number,
         }
 
         #[derive(Debug, Serialize, Deserialize)]
@@ -935,8 +2208,204 @@ pub mod exchange {
         #[serde(rename_all = "camelCase", tag = "status", content = "response")]
         pub enum Response {
             Ok(Data),
+            /// Rejected action, only decoded from raw responses: `Exchange` returns rejections
+            /// as errors, see [`Error::from_response`]
             Err(String),
         }
+
+        impl Response {
+            /// Statuses returned for a batch action, e.g. one per order placed
+            ///
+            /// Top-level rejections are returned as [`Error::ExchangeError`]
+            pub fn into_statuses(self) -> Result<Vec<Status>> {
+                match self {
+                    Response::Ok(Data {
+                        data: Some(StatusType::Statuses(statuses)),
+                        ..
+                    }) => Ok(statuses),
+                    Response::Ok(Data {
+                        data: Some(StatusType::Status(status)),
+                        ..
+                    }) => Ok(vec![status]),
+                    Response::Ok(data) => Err(Error::UnexpectedResponse(format!("{data:?}"))),
+                    Response::Err(error) => Err(Error::ExchangeError(error)),
+                }
+            }
+
+            /// Map the statuses of an order action to the orders that were submitted
+            ///
+            /// # Arguments
+            /// * `orders` - The orders submitted, in the same order as they were placed
+            pub fn into_order_results(self, orders: &[OrderRequest]) -> Result<Vec<OrderResult>> {
+                let statuses = self.into_statuses()?;
+
+                if statuses.len() != orders.len() {
+                    return Err(Error::UnexpectedResponse(format!(
+                        "expected {} statuses, got {}",
+                        orders.len(),
+                        statuses.len()
+                    )));
+                }
+
+                Ok(orders
+                    .iter()
+                    .zip(statuses)
+                    .map(|(order, status)| OrderResult {
+                        asset: order.asset,
+                        cloid: order.cloid,
+                        status: status.into(),
+                    })
+                    .collect())
+            }
+
+            /// Map the statuses of a cancel action to the orders that were cancelled
+            ///
+            /// # Arguments
+            /// * `cancels` - The cancels submitted, in the same order as they were sent
+            pub fn into_cancel_results(
+                self,
+                cancels: &[CancelRequest],
+            ) -> Result<Vec<CancelResult>> {
+                let statuses = self.into_statuses()?;
+
+                if statuses.len() != cancels.len() {
+                    return Err(Error::UnexpectedResponse(format!(
+                        "expected {} statuses, got {}",
+                        cancels.len(),
+                        statuses.len()
+                    )));
+                }
+
+                Ok(cancels
+                    .iter()
+                    .zip(statuses)
+                    .map(|(cancel, status)| CancelResult {
+                        asset: Some(cancel.asset),
+                        oid: cancel.oid,
+                        error: match status {
+                            Status::Success => None,
+                            Status::Error(error) => Some(error),
+                            status => Some(format!("Unexpected cancel status: {status:?}")),
+                        },
+                    })
+                    .collect())
+            }
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum OrderErrorKind {
+            /// Order value is below the minimum trade notional
+            MinTradeNtl,
+            InsufficientMargin,
+            /// IOC order could not immediately match against any resting orders
+            IocCancel,
+            /// Post only order would have immediately matched
+            BadAloPx,
+            /// Price is not divisible by the tick size
+            TickSize,
+            /// Reduce only order would increase position
+            ReduceOnly,
+            Other,
+        }
+
+        #[derive(Debug, Clone)]
+        pub struct OrderError {
+            pub kind: OrderErrorKind,
+            pub message: String,
+        }
+
+        impl From<String> for OrderError {
+            fn from(message: String) -> Self {
+                let kind = if message.contains("minimum value") {
+                    OrderErrorKind::MinTradeNtl
+                } else if message.contains("Insufficient margin") {
+                    OrderErrorKind::InsufficientMargin
+                } else if message.contains("could not immediately match") {
+                    OrderErrorKind::IocCancel
+                } else if message.contains("Post only order would have immediately matched") {
+                    OrderErrorKind::BadAloPx
+                } else if message.contains("tick size") {
+                    OrderErrorKind::TickSize
+                } else if message.contains("Reduce only order would increase position") {
+                    OrderErrorKind::ReduceOnly
+                } else {
+                    OrderErrorKind::Other
+                };
+
+                Self { kind, message }
+            }
+        }
+
+        #[derive(Debug)]
+        pub enum OrderPlacementStatus {
+            Resting(Resting),
+            Filled(Filled),
+            WaitingForFill,
+            WaitingForTrigger,
+            Error(OrderError),
+        }
+
+        impl From<Status> for OrderPlacementStatus {
+            fn from(status: Status) -> Self {
+                match status {
+                    Status::Resting(resting) => OrderPlacementStatus::Resting(resting),
+                    Status::Filled(filled) => OrderPlacementStatus::Filled(filled),
+                    Status::WaitingForFill => OrderPlacementStatus::WaitingForFill,
+                    Status::WaitingForTrigger => OrderPlacementStatus::WaitingForTrigger,
+                    Status::Error(error) => OrderPlacementStatus::Error(error.into()),
+                    status => OrderPlacementStatus::Error(OrderError {
+                        kind: OrderErrorKind::Other,
+                        message: format!("Unexpected order status: {status:?}"),
+                    }),
+                }
+            }
+        }
+
+        /// Result of a single cancel in a batch
+        #[derive(Debug)]
+        pub struct CancelResult {
+            /// `None` if the coin of the order could not be resolved to an asset
+            pub asset: Option<u32>,
+            pub oid: u64,
+            pub error: Option<String>,
+        }
+
+        impl CancelResult {
+            /// Returns `true` if the order was not cancelled
+            pub fn is_err(&self) -> bool {
+                self.error.is_some()
+            }
+        }
+
+        /// Result of a single order in a batch
+        #[derive(Debug)]
+        pub struct OrderResult {
+            pub asset: u32,
+            pub cloid: Option<Cloid>,
+            pub status: OrderPlacementStatus,
+        }
+
+        impl OrderResult {
+            /// The order id if the order is resting or filled
+            pub fn oid(&self) -> Option<u64> {
+                match &self.status {
+                    OrderPlacementStatus::Resting(resting) => Some(resting.oid),
+                    OrderPlacementStatus::Filled(filled) => Some(filled.oid),
+                    _ => None,
+                }
+            }
+
+            pub fn is_err(&self) -> bool {
+                matches!(self.status, OrderPlacementStatus::Error(_))
+            }
+
+            pub fn error(&self) -> Option<&OrderError> {
+                match &self.status {
+                    OrderPlacementStatus::Error(error) => Some(error),
+                    _ => None,
+                }
+            }
+        }
     }
 }
 
@@ -956,6 +2425,9 @@ pub mod websocket {
             L2Book { coin: String },
             Trades { coin: String },
             Candle { coin: String, interval: String },
+            TwapStates { user: Address },
+            UserTwapSliceFills { user: Address },
+            UserTwapHistory { user: Address },
         }
 
         #[derive(Clone)]
@@ -987,7 +2459,10 @@ pub mod websocket {
         use serde_json::Value;
 
         use crate::types::{
-            info::response::{CandleSnapshot, Ctx, Universe, UserFill, UserState},
+            info::response::{
+                CandleSnapshot, Ctx, TwapHistory, TwapSliceFill, TwapState, Universe, UserFill,
+                UserState,
+            },
             Side,
         };
 
@@ -1111,6 +2586,31 @@ pub mod websocket {
             WsNonUserCancel(Vec<WsNonUserCancel>),
         }
 
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct WsTwapStates {
+            pub user: Address,
+            /// Running TWAPs as `(twap_id, state)` pairs
+            pub states: Vec<(u64, TwapState)>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct WsUserTwapSliceFills {
+            #[serde(default)]
+            pub is_snapshot: bool,
+            pub user: Address,
+            pub twap_slice_fills: Vec<TwapSliceFill>,
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        pub struct WsUserTwapHistory {
+            #[serde(default)]
+            pub is_snapshot: bool,
+            pub user: Address,
+            pub history: Vec<TwapHistory>,
+        }
+
         #[derive(Debug, Serialize, Deserialize)]
         pub struct Channel {
             pub method: String,
@@ -1119,6 +2619,7 @@ pub mod websocket {
 
         #[derive(Debug, Serialize, Deserialize)]
         #[serde(rename_all = "camelCase", tag = "channel", content = "data")]
+        #[allow(clippy::large_enum_variant)]
         pub enum Response {
             AllMids(AllMids),
             Notification(Notification),
@@ -1128,6 +2629,9 @@ pub mod websocket {
             Trades(Vec<WsTrade>),
             OrderUpdates(Vec<WsOrder>),
             User(WsUserEvent),
+            TwapStates(WsTwapStates),
+            UserTwapSliceFills(WsUserTwapSliceFills),
+            UserTwapHistory(WsUserTwapHistory),
             SubscriptionResponse(Channel),
         }
     }
@@ -1,8 +1,12 @@
 use serde::Serializer;
 use uuid::Uuid;
 
+use crate::assets::AssetKind;
+
 /// Parse price to the accepted number of decimals
-/// Prices can have up to 5 significant figures, but no more than 6 decimals places
+/// Prices can have up to 5 significant figures, but no more than 6 decimals places.
+/// Prices are truncated, use [`round_price`] to account for the asset kind, its size decimals
+/// and the rounding direction.
 ///
 /// # Examples
 /// ```
@@ -13,26 +17,108 @@ use uuid::Uuid;
 /// assert_eq!(parse_price(0.001234), "0.001234");
 /// assert_eq!(parse_price(0.0012345), "0.001234");
 /// assert_eq!(parse_price(1.2345678), "1.2345");
+/// assert_eq!(parse_price(123456.7), "123456");
 /// ```
 pub fn parse_price(px: f64) -> String {
-    let px = format!("{px:.6}");
+    round_price(px, AssetKind::Perp, 0, RoundingMode::Down)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards zero, e.g. for buy limit prices
+    Down,
+    /// Round away from zero, e.g. for sell limit prices
+    Up,
+    /// Round half away from zero
+    Nearest,
+}
+
+/// Round a price to a valid tick.
+/// Prices can have up to 5 significant figures, but no more than `6 - sz_decimals` decimal places
+/// for perps and `8 - sz_decimals` for spot. Integer prices are always valid.
+///
+/// # Examples
+/// ```
+/// use hyperliquid::{utils::{round_price, RoundingMode}, AssetKind};
+///
+/// assert_eq!(round_price(1234.56, AssetKind::Perp, 4, RoundingMode::Down), "1234.5");
+/// assert_eq!(round_price(1234.56, AssetKind::Perp, 4, RoundingMode::Up), "1234.6");
+/// assert_eq!(round_price(0.0012345, AssetKind::Perp, 0, RoundingMode::Nearest), "0.001235");
+/// assert_eq!(round_price(0.0012345, AssetKind::Perp, 2, RoundingMode::Nearest), "0.0012");
+/// assert_eq!(round_price(0.0012345, AssetKind::Spot, 0, RoundingMode::Nearest), "0.0012345");
+/// assert_eq!(round_price(123456.7, AssetKind::Perp, 5, RoundingMode::Up), "123457");
+/// ```
+pub fn round_price(px: f64, kind: AssetKind, sz_decimals: u32, mode: RoundingMode) -> String {
+    if !px.is_finite() || px <= 0.0 {
+        return "0".to_string();
+    }
+
+    let max_decimals: u32 = match kind {
+        AssetKind::Perp => 6,
+        AssetKind::Spot => 8,
+    };
+    let max_decimals = max_decimals.saturating_sub(sz_decimals);
+
+    let decimals = sig_fig_decimals(px, 5).min(max_decimals);
+
+    round_to_decimals(px, decimals, mode)
+}
+
+/// Round a size to the szDecimals of the asset
+///
+/// # Examples
+/// ```
+/// use hyperliquid::utils::{round_size, RoundingMode};
+///
+/// assert_eq!(round_size(1.0019, 3, RoundingMode::Down), "1.001");
+/// assert_eq!(round_size(1.0011, 3, RoundingMode::Up), "1.002");
+/// assert_eq!(round_size(1.0015, 3, RoundingMode::Nearest), "1.002");
+/// ```
+pub fn round_size(sz: f64, sz_decimals: u32, mode: RoundingMode) -> String {
+    if !sz.is_finite() || sz <= 0.0 {
+        return "0".to_string();
+    }
+
+    round_to_decimals(sz, sz_decimals, mode)
+}
+
+/// Number of decimals needed to keep `sig_figs` significant figures of `value`
+fn sig_fig_decimals(value: f64, sig_figs: u32) -> u32 {
+    if value >= 1.0 {
+        let int_digits = format!("{:.0}", value.trunc()).len() as u32;
 
-    let px = if px.starts_with("0.") {
-        px
+        sig_figs.saturating_sub(int_digits)
     } else {
-        let px: Vec<&str> = px.split('.').collect();
-        let whole = px[0];
-        let decimals = px[1];
+        let leading_zeros = format!("{value:.20}")[2..]
+            .chars()
+            .take_while(|c| *c == '0')
+            .count() as u32;
 
-        let diff = 5 - whole.len(); // 0
-        let sep = if diff > 0 { "." } else { "" };
+        leading_zeros + sig_figs
+    }
+}
+
+fn round_to_decimals(value: f64, decimals: u32, mode: RoundingMode) -> String {
+    let scale = 10f64.powi(decimals as i32);
+    let scaled = value * scale;
+
+    // snap values like 1234.5 * 10 = 12344.999999999998 back to the intended integer
+    let nearest = scaled.round();
+    let scaled = if (scaled - nearest).abs() <= scaled.abs() * 1e-12 {
+        nearest
+    } else {
+        scaled
+    };
 
-        format!("{whole}{sep}{decimals:.0$}", diff)
+    let scaled = match mode {
+        RoundingMode::Down => scaled.floor(),
+        RoundingMode::Up => scaled.ceil(),
+        RoundingMode::Nearest => scaled.round(),
     };
 
-    let px = remove_trailing_zeros(&px);
+    let value = format!("{:.1$}", scaled / scale, decimals as usize);
 
-    positive(px)
+    remove_trailing_zeros(&value)
 }
 
 /// Parse size to the accepted number of decimals.
@@ -49,7 +135,6 @@ pub fn parse_price(px: f64) -> String {
 /// assert_eq!(parse_size(1.0001, 3), "1");
 /// assert_eq!(parse_size(1000.0, 0), "1000");
 /// ```
-
 pub fn parse_size(sz: f64, sz_decimals: u32) -> String {
     let sz = format!("{sz:.0$}", sz_decimals as usize);
 
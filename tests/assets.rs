@@ -0,0 +1,79 @@
+use hyperliquid::{
+    types::info::response::{SpotMeta, Universe},
+    AssetKind, AssetRegistry, Error,
+};
+use serde_json::json;
+
+fn meta() -> Universe {
+    serde_json::from_value(json!({
+        "universe": [
+            { "name": "BTC", "szDecimals": 5, "maxLeverage": 50, "onlyIsolated": false },
+            { "name": "ETH", "szDecimals": 4, "maxLeverage": 50, "onlyIsolated": false },
+        ]
+    }))
+    .unwrap()
+}
+
+fn spot_meta() -> SpotMeta {
+    serde_json::from_value(json!({
+        "tokens": [
+            { "name": "USDC", "szDecimals": 8, "weiDecimals": 8, "index": 0, "tokenId": "0x6d1e7cde53ba9467b783cb7c530ce054", "isCanonical": true },
+            { "name": "PURR", "szDecimals": 0, "weiDecimals": 5, "index": 1, "tokenId": "0xc1fb593aeffbeb02f85e0308e9956a90", "isCanonical": true },
+            { "name": "HFUN", "szDecimals": 2, "weiDecimals": 8, "index": 2, "tokenId": "0xbaf265ef389da684513d98d68edf4eae", "isCanonical": false },
+        ],
+        "universe": [
+            { "name": "PURR/USDC", "tokens": [1, 0], "index": 0, "isCanonical": true },
+            { "name": "@107", "tokens": [2, 0], "index": 107, "isCanonical": false },
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_resolve_perp() {
+    let registry = AssetRegistry::new(&meta(), &spot_meta());
+
+    let eth = registry.resolve("ETH").unwrap();
+    assert_eq!(eth.id, 1);
+    assert_eq!(eth.sz_decimals, 4);
+    assert_eq!(eth.kind, AssetKind::Perp);
+
+    assert_eq!(registry.asset("BTC").unwrap(), 0);
+    assert_eq!(registry.sz_decimals("BTC").unwrap(), 5);
+}
+
+#[test]
+fn test_resolve_spot() {
+    let registry = AssetRegistry::new(&meta(), &spot_meta());
+
+    let purr = registry.resolve("PURR/USDC").unwrap();
+    assert_eq!(purr.id, 10_000);
+    assert_eq!(purr.sz_decimals, 0);
+    assert_eq!(purr.kind, AssetKind::Spot);
+    assert_eq!(registry.resolve("@0").unwrap(), purr);
+
+    let hfun = registry.resolve("@107").unwrap();
+    assert_eq!(hfun.id, 10_107);
+    assert_eq!(hfun.sz_decimals, 2);
+
+    assert_eq!(registry.by_id(10_107).unwrap().name, "@107");
+}
+
+#[test]
+fn test_resolve_unknown() {
+    let registry = AssetRegistry::new(&meta(), &spot_meta());
+
+    assert!(matches!(
+        registry.resolve("DOGE"),
+        Err(Error::AssetNotFound(coin)) if coin == "DOGE"
+    ));
+}
+
+#[test]
+fn test_update() {
+    let registry = AssetRegistry::default();
+    assert!(registry.resolve("ETH").is_err());
+
+    registry.update(&meta(), &spot_meta());
+    assert_eq!(registry.asset("ETH").unwrap(), 1);
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use ethers::{core::rand::thread_rng, signers::LocalWallet, types::Address};
+use hyperliquid::{
+    types::exchange::{
+        request::{Action, CancelRequest},
+        response::Response,
+    },
+    Error,
+};
+use serde_json::json;
+
+use common::Server;
+
+mod common;
+
+fn cancel() -> Action {
+    Action::Cancel {
+        cancels: vec![CancelRequest { asset: 4, oid: 1 }],
+    }
+}
+
+async fn submit(server: &Server) -> hyperliquid::Result<Response> {
+    let exchange = server.exchange();
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    let request = exchange.sign_action(wallet, cancel(), None).await?;
+
+    exchange.submit(&request).await
+}
+
+fn err(message: &str) -> Option<(u16, String)> {
+    Some((
+        200,
+        json!({ "status": "err", "response": message }).to_string(),
+    ))
+}
+
+#[tokio::test]
+async fn test_http_error_keeps_body() {
+    let server = Server::start(|_| Some((500, "internal error".to_string()))).await;
+
+    let error = server.info().mids().await.unwrap_err();
+
+    assert!(matches!(&error, Error::Http(500, body) if body == "internal error"));
+    assert!(error.is_transient());
+}
+
+#[tokio::test]
+async fn test_dropped_connection_is_not_transient() {
+    let server = Server::start(|_| None).await;
+
+    let error = server.info().mids().await.unwrap_err();
+
+    // the request was sent, but failed for a reason other than a connect failure, a timeout or
+    // a server error
+    assert!(matches!(&error, Error::Reqwest(e) if e.is_request() && !e.is_connect()));
+    assert!(!error.is_transient());
+}
+
+#[tokio::test]
+async fn test_rate_limited() {
+    let server = Server::start(|_| Some((429, "null".to_string()))).await;
+
+    let error = server.info().mids().await.unwrap_err();
+
+    assert!(matches!(error, Error::RateLimited(_)));
+    assert!(error.is_not_executed());
+    assert_eq!(server.requests("/info"), 1);
+}
+
+#[tokio::test]
+async fn test_decode_error_keeps_body() {
+    let server = Server::start(|_| Some((200, "not json".to_string()))).await;
+
+    let error = server.info().mids().await.unwrap_err();
+
+    assert!(matches!(error, Error::Decode(_, body) if body == "not json"));
+}
+
+#[tokio::test]
+async fn test_known_exchange_failures() {
+    for (message, expected) in [
+        (
+            "Invalid nonce: duplicate nonce",
+            Error::InvalidNonce(String::new()),
+        ),
+        (
+            "User or API Wallet 0x0d1d9635d0640821d15e323ac8adadfa9c111414 does not exist.",
+            Error::InvalidSignature(String::new()),
+        ),
+        (
+            "Must deposit before performing actions. User: 0x0d1d9635d0640821d15e323ac8adadfa9c111414",
+            Error::UnknownUser(String::new()),
+        ),
+        (
+            "Too many cumulative requests sent",
+            Error::RateLimited(String::new()),
+        ),
+    ] {
+        let server = Server::start(move |_| err(message)).await;
+
+        let error = submit(&server).await.unwrap_err();
+
+        assert_eq!(
+            std::mem::discriminant(&error),
+            std::mem::discriminant(&expected),
+            "{message}"
+        );
+        assert!(error.to_string().contains(message));
+    }
+}
+
+#[tokio::test]
+async fn test_other_exchange_failures_are_errors() {
+    let server = Server::start(|_| err("Order has invalid price.")).await;
+
+    let error = submit(&server).await.unwrap_err();
+
+    assert!(
+        matches!(error, Error::ExchangeError(message) if message == "Order has invalid price.")
+    );
+}
+
+#[tokio::test]
+async fn test_info_status_code() {
+    let server =
+        Server::start(|_| Some((422, "Failed to deserialize the JSON body".to_string()))).await;
+
+    let error = server.info().user_state(Address::zero()).await.unwrap_err();
+
+    assert!(matches!(error, Error::Http(422, _)));
+    assert!(!error.is_transient());
+}
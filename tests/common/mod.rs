@@ -0,0 +1,114 @@
+use std::sync::{Arc, Mutex};
+
+use hyperliquid::{types::Chain, Config, Exchange, Hyperliquid, Info};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// Minimal HTTP server standing in for the API, answering each request with the status and
+/// body returned by its handler for the request path, or dropping the connection without a
+/// response (e.g. like a connection reset) if the handler returns `None`
+pub struct Server {
+    pub address: String,
+    /// Path and raw head of every request received
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl Server {
+    pub async fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&str) -> Option<(u16, String)> + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let received = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let (path, head) = read_request(&mut stream).await;
+
+                received.lock().unwrap().push((path.clone(), head));
+
+                let Some((status, body)) = handler(&path) else {
+                    continue;
+                };
+
+                let response = format!(
+                    "HTTP/1.1 {status} Status\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len(),
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        Self { address, requests }
+    }
+
+    /// Number of requests received on `path`, e.g. `/exchange`
+    pub fn requests(&self, path: &str) -> usize {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(received, _)| received == path)
+            .count()
+    }
+
+    /// Raw head (request line and headers) of the last request received, lowercased
+    #[allow(dead_code)]
+    pub fn last_request(&self) -> Option<String> {
+        self.requests
+            .lock()
+            .unwrap()
+            .last()
+            .map(|(_, head)| head.to_lowercase())
+    }
+
+    pub fn config(&self) -> Config {
+        let mut config = Config::local();
+        config.set_rest_endpoint(self.address.clone());
+        config
+    }
+
+    #[allow(dead_code)]
+    pub fn info(&self) -> Info {
+        Info::new_with_config(Chain::ArbitrumTestnet, &self.config())
+    }
+
+    #[allow(dead_code)]
+    pub fn exchange(&self) -> Exchange {
+        Exchange::new_with_config(Chain::ArbitrumTestnet, &self.config())
+    }
+}
+
+async fn read_request(stream: &mut TcpStream) -> (String, String) {
+    let mut request = vec![];
+    let mut buf = [0; 4096];
+
+    loop {
+        let n = stream.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+
+        let text = String::from_utf8_lossy(&request);
+        if let Some(end) = text.find("\r\n\r\n") {
+            let length = text
+                .lines()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")
+                        .map(|length| length.parse::<usize>().unwrap())
+                })
+                .unwrap_or_default();
+
+            if request.len() >= end + 4 + length || n == 0 {
+                return (
+                    text.split_whitespace().nth(1).unwrap().to_string(),
+                    text[..end].to_string(),
+                );
+            }
+        }
+    }
+}
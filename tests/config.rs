@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use hyperliquid::{
+    types::{info::request::Request, Chain, API},
+    Config, Error, Exchange, Hyperliquid, Info, ReqwestTransport, USER_AGENT,
+};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Proxy,
+};
+use tokio::net::TcpListener;
+
+use common::Server;
+
+mod common;
+
+fn ok(_: &str) -> Option<(u16, String)> {
+    Some((200, "{}".to_string()))
+}
+
+#[tokio::test]
+async fn test_extra_headers_and_user_agent() {
+    let server = Server::start(ok).await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-api-key", HeaderValue::from_static("secret"));
+
+    let mut config = server.config();
+    config.set_headers(headers);
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+    info.mids().await.unwrap();
+
+    let request = server.last_request().unwrap();
+
+    assert!(request.contains("x-api-key: secret"));
+    assert!(request.contains(&format!("user-agent: {USER_AGENT}")));
+    assert!(request.contains("content-type: application/json"));
+}
+
+#[tokio::test]
+async fn test_injected_client_is_shared() {
+    let server = Server::start(ok).await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-client", HeaderValue::from_static("shared"));
+
+    let mut config = server.config();
+    config.set_http_client(
+        reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap(),
+    );
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    info.mids().await.unwrap();
+    assert!(server.last_request().unwrap().contains("x-client: shared"));
+
+    exchange
+        .client
+        .post::<serde_json::Value>(&API::Info, &Request::AllMids)
+        .await
+        .unwrap();
+    assert!(server.last_request().unwrap().contains("x-client: shared"));
+    assert_eq!(server.requests("/info"), 2);
+}
+
+#[tokio::test]
+async fn test_request_timeout() {
+    // accepts connections but never responds
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut streams = vec![];
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            streams.push(stream);
+        }
+    });
+
+    let mut config = Config::local();
+    config.set_rest_endpoint(address);
+    config.set_request_timeout(Duration::from_millis(100));
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    let start = Instant::now();
+    let error = info.mids().await.unwrap_err();
+
+    assert!(matches!(&error, Error::Reqwest(e) if e.is_timeout()));
+    assert!(error.is_transient());
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn test_proxy() {
+    let server = Server::start(ok).await;
+
+    let mut config = Config::local();
+    config.set_rest_endpoint("http://api.hyperliquid.invalid".to_string());
+    config.set_proxy(Proxy::http(&server.address).unwrap());
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+    info.mids().await.unwrap();
+
+    assert_eq!(server.requests("http://api.hyperliquid.invalid/info"), 1);
+}
+
+#[test]
+fn test_default_config() {
+    let config = Config::mainnet();
+
+    assert!(config.http_client.is_none());
+    assert!(config.request_timeout.is_none());
+    assert!(config.headers.is_empty());
+    assert!(config.http_client().is_ok());
+    assert!(ReqwestTransport::new(&config).is_ok());
+
+    let _info: Info = Hyperliquid::new(Chain::Arbitrum);
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "decimal")]
+
+use hyperliquid::{
+    from_decimal,
+    types::{
+        exchange::request::{Limit, OrderRequest, OrderType, Tif},
+        info::response::{Level, UserFill},
+    },
+    Error, ToDecimal,
+};
+use rust_decimal::Decimal;
+use serde_json::json;
+
+#[test]
+fn test_response_numbers_to_decimals() {
+    let level: Level =
+        serde_json::from_value(json!({ "px": "2805.10", "sz": "12.5", "n": 3 })).unwrap();
+
+    let (px, sz) = (
+        level.px.to_decimal().unwrap(),
+        level.sz.to_decimal().unwrap(),
+    );
+
+    assert_eq!(px, "2805.1".parse::<Decimal>().unwrap());
+    assert_eq!(sz * px, "35063.75".parse::<Decimal>().unwrap());
+
+    // the wire format is untouched
+    assert_eq!(
+        serde_json::to_value(&level).unwrap(),
+        json!({ "px": "2805.10", "sz": "12.5", "n": 3 })
+    );
+
+    let fill: UserFill = serde_json::from_value(json!({
+        "coin": "ETH",
+        "px": "2805.1",
+        "sz": "0.1",
+        "side": "B",
+        "time": 1700000000000u64,
+        "startPosition": "-0.1",
+        "dir": "Close Short",
+        "closedPnl": "-1.234567",
+        "hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "oid": 1,
+        "crossed": true,
+        "fee": "0.1234"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        fill.closed_pnl.to_decimal().unwrap() - fill.fee.to_decimal().unwrap(),
+        "-1.357967".parse::<Decimal>().unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_numbers_are_errors() {
+    for invalid in ["", "abc", "1.2.3", "100000000000000000000000000000000"] {
+        assert!(
+            matches!(invalid.to_decimal(), Err(Error::InvalidNumber(_))),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn test_order_numbers_are_normalized() {
+    let order = OrderRequest {
+        asset: 4,
+        is_buy: true,
+        limit_px: from_decimal("1800.00".parse().unwrap()),
+        sz: from_decimal("0.100".parse().unwrap()),
+        reduce_only: false,
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid: None,
+    };
+
+    let value = serde_json::to_value(&order).unwrap();
+
+    assert_eq!(value["p"], "1800");
+    assert_eq!(value["s"], "0.1");
+}
@@ -0,0 +1,963 @@
+use std::{sync::Arc, time::Duration};
+
+use ethers::{
+    core::rand::thread_rng,
+    signers::{LocalWallet, Signer},
+    types::{transaction::eip712::Eip712, Address},
+};
+use hyperliquid::{
+    types::{
+        agent::l1,
+        exchange::request::{
+            Action, CancelFilter, Grouping, Limit, MultiSigBundle, MultiSigSigners, OrderRequest,
+            OrderType, Request, Tif, TpSl, Trigger, UsdSend,
+        },
+        Chain, HyperliquidChain, API,
+    },
+    utils::{parse_price, parse_size},
+    AssetRegistry, Config, Error, Exchange, Hyperliquid, Info, MemoryTransport,
+};
+use serde_json::json;
+
+fn wallet() -> Arc<LocalWallet> {
+    // Key was randomly generated for testing and shouldn't be used with any real funds
+    Arc::new(
+        "e908f86dbb4d55ac876378565aafeabc187f6690f046459397b17d9b9a19688e"
+            .parse()
+            .unwrap(),
+    )
+}
+
+fn order(asset: u32) -> OrderRequest {
+    OrderRequest {
+        asset,
+        is_buy: true,
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
+        reduce_only: false,
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid: None,
+    }
+}
+
+fn order_action() -> Action {
+    Action::Order {
+        orders: vec![order(4)],
+        grouping: Grouping::Na,
+        builder: None,
+    }
+}
+
+#[tokio::test]
+async fn test_sign_l1_action_offline() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = wallet();
+    let vault_address: Address = "0x0D1d9635D0640821d15e323ac8AdADfA9c111414"
+        .parse()
+        .unwrap();
+
+    let request = exchange
+        .sign_action(wallet.clone(), order_action(), Some(vault_address))
+        .await
+        .unwrap();
+
+    assert_eq!(request.vault_address, Some(vault_address));
+
+    // round trip through the wire format, e.g. from a cold wallet to a relay
+    let json = serde_json::to_string(&request).unwrap();
+    let relayed: Request = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(serde_json::to_string(&relayed).unwrap(), json);
+
+    let connection_id = relayed
+        .action
+        .connection_id(relayed.vault_address, relayed.nonce, relayed.expires_after)
+        .unwrap();
+    let agent = l1::Agent {
+        source: "b".to_string(),
+        connection_id,
+    };
+
+    let signer = relayed
+        .signature
+        .recover(agent.encode_eip712().unwrap())
+        .unwrap();
+
+    assert_eq!(signer, wallet.address());
+}
+
+#[tokio::test]
+async fn test_sign_user_action_offline() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = wallet();
+
+    let time = exchange.next_nonce(wallet.address()).unwrap();
+    let payload = UsdSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: exchange.hyperliquid_chain().unwrap(),
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        amount: "1".to_string(),
+        time,
+    };
+    let digest = payload.encode_eip712().unwrap();
+
+    let request = exchange
+        .sign_action(wallet.clone(), Action::UsdSend(payload), None)
+        .await
+        .unwrap();
+
+    assert_eq!(request.nonce, time);
+    assert_eq!(request.vault_address, None);
+    assert_eq!(request.signature.recover(digest).unwrap(), wallet.address());
+}
+
+#[tokio::test]
+async fn test_sign_unsupported_chain() {
+    let exchange: Exchange = Hyperliquid::new(Chain::Dev);
+
+    assert!(exchange
+        .sign_action(wallet(), order_action(), None)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_recover_signer() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = wallet();
+    let agent = LocalWallet::new(&mut thread_rng());
+
+    let request = exchange
+        .sign_action(wallet.clone(), order_action(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        request.recover_signer(HyperliquidChain::Testnet).unwrap(),
+        wallet.address()
+    );
+    assert_ne!(
+        request.recover_signer(HyperliquidChain::Mainnet).unwrap(),
+        wallet.address()
+    );
+
+    assert_eq!(
+        request
+            .verify(
+                HyperliquidChain::Testnet,
+                &[agent.address(), wallet.address()]
+            )
+            .unwrap(),
+        wallet.address()
+    );
+    assert!(matches!(
+        request.verify(HyperliquidChain::Testnet, &[agent.address()]),
+        Err(Error::UnauthorizedSigner(signer)) if signer == wallet.address()
+    ));
+}
+
+#[tokio::test]
+async fn test_recover_user_signed_tampered() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = wallet();
+
+    let payload = UsdSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: exchange.hyperliquid_chain().unwrap(),
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        amount: "1".to_string(),
+        time: exchange.next_nonce(wallet.address()).unwrap(),
+    };
+
+    let mut request = exchange
+        .sign_action(wallet.clone(), Action::UsdSend(payload), None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        request.recover_signer(HyperliquidChain::Testnet).unwrap(),
+        wallet.address()
+    );
+
+    if let Action::UsdSend(payload) = &mut request.action {
+        payload.amount = "1000".to_string();
+    }
+
+    assert!(request
+        .verify(HyperliquidChain::Testnet, &[wallet.address()])
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_sign_action_with_own_nonce() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = wallet();
+
+    let action = Action::CreateVault {
+        name: "Vault".to_string(),
+        description: "Description".to_string(),
+        initial_usd: 100_000_000,
+        nonce: 1700000000000,
+    };
+
+    let request = exchange
+        .sign_action(wallet.clone(), action, None)
+        .await
+        .unwrap();
+
+    assert_eq!(request.nonce, 1700000000000);
+    assert_eq!(
+        request.recover_signer(HyperliquidChain::Testnet).unwrap(),
+        wallet.address()
+    );
+}
+
+#[tokio::test]
+async fn test_position_tpsl_rejects_invalid_orders() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let info: Info = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let assets = AssetRegistry::new(
+        &serde_json::from_value(json!({ "universe": [] })).unwrap(),
+        &serde_json::from_value(json!({ "tokens": [], "universe": [] })).unwrap(),
+    );
+
+    assert!(matches!(
+        exchange
+            .position_tpsl(wallet(), &info, &assets, vec![], None)
+            .await,
+        Err(Error::InvalidOrder(_))
+    ));
+    assert!(matches!(
+        exchange
+            .position_tpsl(wallet(), &info, &assets, vec![order(0), order(1)], None)
+            .await,
+        Err(Error::InvalidOrder(_))
+    ));
+    assert!(matches!(
+        exchange
+            .position_tpsl(wallet(), &info, &assets, vec![order(4)], None)
+            .await,
+        Err(Error::AssetNotFound(_))
+    ));
+}
+
+fn memory_config(transport: &MemoryTransport) -> Config {
+    let mut config = Config::testnet();
+    config.set_transport(Arc::new(transport.clone()));
+    config
+}
+
+fn assets() -> AssetRegistry {
+    AssetRegistry::new(
+        &serde_json::from_value(json!({
+            "universe": [
+                { "name": "BTC", "szDecimals": 5, "maxLeverage": 50, "onlyIsolated": false },
+                { "name": "ETH", "szDecimals": 4, "maxLeverage": 50, "onlyIsolated": false },
+            ]
+        }))
+        .unwrap(),
+        &serde_json::from_value(json!({ "tokens": [], "universe": [] })).unwrap(),
+    )
+}
+
+fn user_state(coin: &str, szi: &str) -> serde_json::Value {
+    let margin_summary = json!({
+        "accountValue": "1000.0",
+        "totalMarginUsed": "0.0",
+        "totalNtlPos": "0.0",
+        "totalRawUsd": "1000.0"
+    });
+
+    json!({
+        "assetPositions": [{
+            "type": "oneWay",
+            "position": {
+                "coin": coin,
+                "cumFunding": { "allTime": "0.0", "sinceChange": "0.0", "sinceOpen": "0.0" },
+                "entryPx": "1800.0",
+                "leverage": { "type": "cross", "value": 20 },
+                "liquidationPx": null,
+                "marginUsed": "45.0",
+                "maxLeverage": 50,
+                "positionValue": "900.0",
+                "returnOnEquity": "0.0",
+                "szi": szi,
+                "unrealizedPnl": "0.0"
+            }
+        }],
+        "marginSummary": margin_summary,
+        "crossMarginSummary": margin_summary,
+        "withdrawable": "1000.0",
+        "time": 1700000000000u64,
+        "crossMaintenanceMarginUsed": "0.0"
+    })
+}
+
+fn tpsl(is_buy: bool, reduce_only: bool) -> OrderRequest {
+    OrderRequest {
+        asset: 1,
+        is_buy,
+        limit_px: parse_price(1700.0),
+        sz: parse_size(0.5, 4),
+        reduce_only,
+        order_type: OrderType::Trigger(Trigger {
+            is_market: true,
+            trigger_px: parse_price(1700.0),
+            tpsl: TpSl::Sl,
+        }),
+        cloid: None,
+    }
+}
+
+#[tokio::test]
+async fn test_position_tpsl_checks_orders_against_position() {
+    let transport = MemoryTransport::new();
+    transport.respond(API::Info, "clearinghouseState", user_state("ETH", "0.5"));
+    transport.respond(
+        API::Exchange,
+        "order",
+        json!({
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": { "statuses": ["waitingForTrigger"] }
+            }
+        }),
+    );
+
+    let config = memory_config(&transport);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+    let assets = assets();
+
+    for invalid in [
+        // not a trigger order
+        order(1),
+        // not reduce-only
+        tpsl(false, false),
+        // same side as the long position
+        tpsl(true, true),
+    ] {
+        assert!(matches!(
+            exchange
+                .position_tpsl(wallet(), &info, &assets, vec![invalid], None)
+                .await,
+            Err(Error::InvalidOrder(_))
+        ));
+    }
+
+    assert!(transport.sent_of(API::Exchange, "order").is_empty());
+
+    exchange
+        .position_tpsl(wallet(), &info, &assets, vec![tpsl(false, true)], None)
+        .await
+        .unwrap();
+
+    let sent = transport.sent_of(API::Exchange, "order");
+
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0]["action"]["grouping"], "positionTpsl");
+    assert_eq!(sent[0]["action"]["orders"][0]["r"], true);
+
+    transport.respond(API::Info, "clearinghouseState", user_state("BTC", "0.1"));
+
+    assert!(matches!(
+        exchange
+            .position_tpsl(wallet(), &info, &assets, vec![tpsl(false, true)], None)
+            .await,
+        Err(Error::NoPosition(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_position_tpsl_for_account_of_agent() {
+    let transport = MemoryTransport::new();
+    transport.respond(API::Info, "clearinghouseState", user_state("ETH", "0.5"));
+    transport.respond(
+        API::Exchange,
+        "order",
+        json!({
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": { "statuses": ["waitingForTrigger"] }
+            }
+        }),
+    );
+
+    let config = memory_config(&transport);
+    let mut exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    let agent = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let account = wallet().address();
+    exchange.set_account(Some(account));
+
+    exchange
+        .position_tpsl(agent, &info, &assets(), vec![tpsl(false, true)], None)
+        .await
+        .unwrap();
+
+    // the orders are checked against the position of the account, not of the agent
+    let queried = transport.sent_of(API::Info, "clearinghouseState");
+
+    assert_eq!(queried[0]["user"], json!(account));
+    assert_eq!(transport.sent_of(API::Exchange, "order").len(), 1);
+}
+
+fn order_response() -> serde_json::Value {
+    json!({
+        "status": "ok",
+        "response": {
+            "type": "order",
+            "data": { "statuses": [{ "filled": { "totalSz": "0.5", "avgPx": "1800.0", "oid": 1 } }] }
+        }
+    })
+}
+
+fn market_transport() -> MemoryTransport {
+    let transport = MemoryTransport::new();
+    transport.respond(API::Info, "allMids", json!({ "ETH": "1801.37" }));
+    transport.respond(API::Info, "clearinghouseState", user_state("ETH", "0.5"));
+    transport.respond(API::Exchange, "order", order_response());
+    transport
+}
+
+#[tokio::test]
+async fn test_market_open_rounds_against_slippage() {
+    let transport = market_transport();
+    let config = memory_config(&transport);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    for is_buy in [true, false] {
+        exchange
+            .market_open(wallet(), &info, &assets(), "ETH", is_buy, 0.5, 0.05, None)
+            .await
+            .unwrap();
+    }
+
+    let sent = transport.sent_of(API::Exchange, "order");
+    let orders: Vec<_> = sent
+        .iter()
+        .map(|request| &request["action"]["orders"][0])
+        .collect();
+
+    // 1801.37 * 1.05 = 1891.4385 rounded down, 1801.37 * 0.95 = 1711.3015 rounded up, so
+    // neither price exceeds the slippage
+    assert_eq!(orders[0]["b"], true);
+    assert_eq!(orders[0]["p"], "1891.4");
+    assert_eq!(orders[1]["b"], false);
+    assert_eq!(orders[1]["p"], "1711.4");
+
+    for order in orders {
+        assert_eq!(order["a"], 1);
+        assert_eq!(order["s"], "0.5");
+        assert_eq!(order["r"], false);
+        assert_eq!(order["t"], json!({ "limit": { "tif": "Ioc" } }));
+    }
+}
+
+#[tokio::test]
+async fn test_market_close_opposes_position() {
+    let transport = market_transport();
+    let config = memory_config(&transport);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    // more than the position, capped at its size
+    exchange
+        .market_close(wallet(), &info, &assets(), "ETH", Some(1.0), 0.05, None)
+        .await
+        .unwrap();
+
+    transport.respond(API::Info, "clearinghouseState", user_state("ETH", "-0.5"));
+
+    exchange
+        .market_close(wallet(), &info, &assets(), "ETH", Some(0.2), 0.05, None)
+        .await
+        .unwrap();
+
+    let sent = transport.sent_of(API::Exchange, "order");
+    let orders: Vec<_> = sent
+        .iter()
+        .map(|request| &request["action"]["orders"][0])
+        .collect();
+
+    // closing a long sells, rounding up
+    assert_eq!(orders[0]["b"], false);
+    assert_eq!(orders[0]["s"], "0.5");
+    assert_eq!(orders[0]["p"], "1711.4");
+
+    // closing a short buys, rounding down
+    assert_eq!(orders[1]["b"], true);
+    assert_eq!(orders[1]["s"], "0.2");
+    assert_eq!(orders[1]["p"], "1891.4");
+
+    for order in orders {
+        assert_eq!(order["r"], true);
+        assert_eq!(order["t"], json!({ "limit": { "tif": "Ioc" } }));
+    }
+
+    transport.respond(API::Info, "clearinghouseState", user_state("BTC", "0.1"));
+
+    assert!(matches!(
+        exchange
+            .market_close(wallet(), &info, &assets(), "ETH", None, 0.05, None)
+            .await,
+        Err(Error::NoPosition(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_market_close_position_of_account() {
+    let transport = market_transport();
+    let config = memory_config(&transport);
+    let mut exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    let agent = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let account = wallet().address();
+    exchange.set_account(Some(account));
+
+    exchange
+        .market_close(agent, &info, &assets(), "ETH", None, 0.05, None)
+        .await
+        .unwrap();
+
+    let queried = transport.sent_of(API::Info, "clearinghouseState");
+
+    assert_eq!(queried[0]["user"], json!(account));
+    assert_eq!(transport.sent_of(API::Exchange, "order").len(), 1);
+}
+
+fn open_order(coin: &str, oid: u64) -> serde_json::Value {
+    json!({
+        "coin": coin,
+        "isPositionTpsl": false,
+        "isTrigger": false,
+        "limitPx": "1800.0",
+        "oid": oid,
+        "orderType": "Limit",
+        "origSz": "0.1",
+        "reduceOnly": false,
+        "side": "B",
+        "sz": "0.1",
+        "timestamp": 1700000000000u64,
+        "triggerCondition": "N/A",
+        "triggerPx": "0.0",
+        "cloid": null,
+    })
+}
+
+#[tokio::test]
+async fn test_cancel_all_reports_failures_per_order() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        API::Info,
+        "frontendOpenOrders",
+        json!([
+            open_order("ETH", 1),
+            open_order("UNKNOWN", 2),
+            open_order("BTC", 3)
+        ]),
+    );
+    transport.respond_with(API::Exchange, "cancel", 502, "Bad Gateway".to_string());
+
+    let config = memory_config(&transport);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    let results = exchange
+        .cancel_all(wallet(), &info, &assets(), CancelFilter::default(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|result| result.is_err()));
+
+    let unknown = results.iter().find(|result| result.oid == 2).unwrap();
+
+    assert_eq!(unknown.asset, None);
+
+    // the unknown coin doesn't prevent the other orders from being cancelled
+    let sent = transport.sent_of(API::Exchange, "cancel");
+
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0]["action"]["cancels"].as_array().unwrap().len(), 2);
+
+    transport.respond(
+        API::Exchange,
+        "cancel",
+        json!({
+            "status": "ok",
+            "response": {
+                "type": "cancel",
+                "data": { "statuses": ["success", { "error": "Order was never placed" }] }
+            }
+        }),
+    );
+
+    let results = exchange
+        .cancel_all(wallet(), &info, &assets(), CancelFilter::default(), None)
+        .await
+        .unwrap();
+
+    let cancelled: Vec<_> = results
+        .iter()
+        .map(|result| (result.oid, result.asset, result.is_err()))
+        .collect();
+
+    assert_eq!(
+        cancelled,
+        vec![(2, None, true), (1, Some(1), false), (3, Some(0), true)]
+    );
+}
+
+#[tokio::test]
+async fn test_cancel_all_for_account_of_agent() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        API::Info,
+        "frontendOpenOrders",
+        json!([open_order("ETH", 1)]),
+    );
+    transport.respond(
+        API::Exchange,
+        "cancel",
+        json!({
+            "status": "ok",
+            "response": { "type": "cancel", "data": { "statuses": ["success"] } }
+        }),
+    );
+
+    let config = memory_config(&transport);
+    let mut exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+
+    let agent = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let account = wallet().address();
+    exchange.set_account(Some(account));
+
+    let results = exchange
+        .cancel_all(agent, &info, &assets(), CancelFilter::default(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].is_err());
+
+    // the open orders are those of the account, not of the agent signing the cancels
+    let queried = transport.sent_of(API::Info, "frontendOpenOrders");
+
+    assert_eq!(queried[0]["user"], json!(account));
+    assert_eq!(transport.sent_of(API::Exchange, "cancel").len(), 1);
+}
+
+fn multi_sig_signers(wallets: &[&LocalWallet], threshold: usize) -> MultiSigSigners {
+    MultiSigSigners {
+        authorized_users: wallets.iter().map(|wallet| wallet.address()).collect(),
+        threshold,
+    }
+}
+
+#[tokio::test]
+async fn test_multi_sig_l1_action() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let multi_sig_user = LocalWallet::new(&mut thread_rng()).address();
+    let (alice, bob, carol) = (
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+    );
+    let outsider = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    let mut bundle = exchange
+        .multi_sig_bundle(
+            multi_sig_user,
+            alice.address(),
+            multi_sig_signers(&[&alice, &bob, &carol], 2),
+            order_action(),
+            None,
+        )
+        .unwrap();
+
+    exchange
+        .sign_multi_sig(alice.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    assert!(!bundle.is_complete());
+    assert!(matches!(
+        exchange.sign_multi_sig(alice.clone(), &mut bundle).await,
+        Err(Error::SignatureError(_))
+    ));
+    assert!(matches!(
+        exchange.sign_multi_sig(outsider.clone(), &mut bundle).await,
+        Err(Error::UnauthorizedSigner(signer)) if signer == outsider.address()
+    ));
+
+    // pass the bundle to the next signer, e.g. on another machine
+    let json = serde_json::to_string(&bundle).unwrap();
+    let mut bundle: MultiSigBundle = serde_json::from_str(&json).unwrap();
+
+    exchange
+        .sign_multi_sig(bob.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    assert!(bundle.is_complete());
+    assert_eq!(
+        bundle.signed_by().unwrap(),
+        vec![alice.address(), bob.address()]
+    );
+
+    let nonce = bundle.nonce;
+
+    let request = exchange
+        .finalize_multi_sig(alice.clone(), bundle)
+        .await
+        .unwrap();
+
+    assert_eq!(request.nonce, nonce);
+    assert_eq!(
+        request.recover_signer(HyperliquidChain::Testnet).unwrap(),
+        alice.address()
+    );
+
+    let action = serde_json::to_value(&request.action).unwrap();
+
+    assert_eq!(action["type"], "multiSig");
+    assert_eq!(action["signatures"].as_array().unwrap().len(), 2);
+    assert_eq!(action["payload"]["action"]["type"], "order");
+}
+
+#[tokio::test]
+async fn test_multi_sig_user_signed_action() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let multi_sig_user = LocalWallet::new(&mut thread_rng()).address();
+    let (alice, bob) = (
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+    );
+
+    let time = exchange.next_nonce(multi_sig_user).unwrap();
+    let payload = UsdSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        amount: "1".to_string(),
+        time,
+    };
+
+    let mut bundle = exchange
+        .multi_sig_bundle(
+            multi_sig_user,
+            bob.address(),
+            multi_sig_signers(&[&alice, &bob], 2),
+            Action::UsdSend(payload),
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(bundle.nonce, time);
+
+    exchange
+        .sign_multi_sig(alice.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    // not enough signatures yet
+    assert!(matches!(
+        exchange.finalize_multi_sig(bob.clone(), bundle).await,
+        Err(Error::MultiSigThreshold(1, 2))
+    ));
+}
+
+#[tokio::test]
+async fn test_multi_sig_wrong_outer_signer() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let multi_sig_user = LocalWallet::new(&mut thread_rng()).address();
+    let (alice, bob) = (
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+    );
+
+    let mut bundle = exchange
+        .multi_sig_bundle(
+            multi_sig_user,
+            alice.address(),
+            multi_sig_signers(&[&alice, &bob], 1),
+            order_action(),
+            None,
+        )
+        .unwrap();
+
+    exchange
+        .sign_multi_sig(bob.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        exchange.finalize_multi_sig(bob.clone(), bundle).await,
+        Err(Error::UnauthorizedSigner(signer)) if signer == bob.address()
+    ));
+}
+
+#[tokio::test]
+async fn test_multi_sig_untrusted_signatures() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let multi_sig_user = LocalWallet::new(&mut thread_rng()).address();
+    let (alice, bob, carol) = (
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+        Arc::new(LocalWallet::new(&mut thread_rng())),
+    );
+    let outsider = LocalWallet::new(&mut thread_rng());
+
+    let mut bundle = exchange
+        .multi_sig_bundle(
+            multi_sig_user,
+            alice.address(),
+            multi_sig_signers(&[&alice, &bob, &carol], 2),
+            order_action(),
+            None,
+        )
+        .unwrap();
+
+    exchange
+        .sign_multi_sig(alice.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    // signatures edited in the serialized bundle bypass `add_signature`
+    let digest = bundle.signing_hash().unwrap();
+    let signature = bundle.signatures[0];
+
+    bundle.signatures.push(signature);
+
+    assert!(!bundle.is_complete());
+
+    bundle.signatures[1] = outsider.sign_hash(digest).unwrap();
+
+    assert!(!bundle.is_complete());
+    assert!(matches!(
+        bundle.into_action(),
+        Err(Error::InvalidSigners(_))
+    ));
+
+    let mut bundle = exchange
+        .multi_sig_bundle(
+            multi_sig_user,
+            alice.address(),
+            multi_sig_signers(&[&alice, &bob, &carol], 2),
+            order_action(),
+            None,
+        )
+        .unwrap();
+
+    exchange
+        .sign_multi_sig(alice.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    let signature = bundle.signatures[0];
+    bundle.signatures.push(signature);
+
+    assert!(matches!(
+        bundle.into_action(),
+        Err(Error::InvalidSigners(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_multi_sig_chain_mismatch() {
+    let testnet: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let mainnet: Exchange = Hyperliquid::new(Chain::Arbitrum);
+    let multi_sig_user = LocalWallet::new(&mut thread_rng()).address();
+    let alice = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    let mut bundle = testnet
+        .multi_sig_bundle(
+            multi_sig_user,
+            alice.address(),
+            multi_sig_signers(&[&alice], 1),
+            order_action(),
+            None,
+        )
+        .unwrap();
+
+    testnet
+        .sign_multi_sig(alice.clone(), &mut bundle)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        mainnet.finalize_multi_sig(alice.clone(), bundle).await,
+        Err(Error::ChainMismatch(_, _))
+    ));
+}
+
+#[tokio::test]
+async fn test_expires_after() {
+    let mut exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    exchange.set_expires_after(Some(Duration::from_secs(60)));
+
+    // each action expires relative to its own nonce
+    for _ in 0..2 {
+        let request = exchange
+            .sign_action(wallet.clone(), order_action(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(request.expires_after, Some(request.nonce + 60_000));
+    }
+
+    let expires_after = 1700000060000;
+
+    let request = exchange
+        .sign_action_with_expiry(wallet.clone(), order_action(), None, Some(expires_after))
+        .await
+        .unwrap();
+
+    assert_eq!(request.expires_after, Some(expires_after));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["expiresAfter"],
+        expires_after
+    );
+    assert_eq!(
+        request.recover_signer(HyperliquidChain::Testnet).unwrap(),
+        wallet.address()
+    );
+
+    // the expiry is part of the signed payload
+    let mut tampered = request;
+    tampered.expires_after = Some(expires_after + 1);
+
+    assert_ne!(
+        tampered.recover_signer(HyperliquidChain::Testnet).unwrap(),
+        wallet.address()
+    );
+
+    // user signed actions don't expire
+    let payload = UsdSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        amount: "1".to_string(),
+        time: 1700000000000,
+    };
+
+    let request = exchange
+        .sign_action(wallet.clone(), Action::UsdSend(payload), None)
+        .await
+        .unwrap();
+
+    assert_eq!(request.expires_after, None);
+    assert!(serde_json::to_value(&request)
+        .unwrap()
+        .get("expiresAfter")
+        .is_none());
+}
@@ -0,0 +1,103 @@
+use std::{collections::HashSet, sync::Arc, thread, time::SystemTime};
+
+use ethers::types::Address;
+use hyperliquid::{NonceManager, NonceProvider};
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
+#[test]
+fn test_nonce_is_strictly_increasing() {
+    let manager = NonceManager::new();
+    let signer = Address::random();
+
+    let mut prev = manager.next_nonce(signer).unwrap();
+    for _ in 0..10_000 {
+        let nonce = manager.next_nonce(signer).unwrap();
+        assert!(nonce > prev);
+        prev = nonce;
+    }
+
+    assert_eq!(manager.last_nonce(signer), Some(prev));
+}
+
+#[test]
+fn test_nonce_unique_under_concurrency() {
+    let manager = Arc::new(NonceManager::new());
+    let signer = Address::random();
+
+    let handles: Vec<_> = (0..16)
+        .map(|_| {
+            let manager = manager.clone();
+            thread::spawn(move || {
+                (0..5_000)
+                    .map(|_| manager.next_nonce(signer).unwrap())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let mut nonces = HashSet::new();
+    for handle in handles {
+        let thread_nonces = handle.join().unwrap();
+        assert!(thread_nonces.windows(2).all(|w| w[0] < w[1]));
+        nonces.extend(thread_nonces);
+    }
+
+    assert_eq!(nonces.len(), 16 * 5_000);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+async fn test_nonce_unique_across_tasks() {
+    let manager: Arc<dyn NonceProvider> = Arc::new(NonceManager::new());
+    let signer = Address::random();
+
+    let handles: Vec<_> = (0..1_000)
+        .map(|_| {
+            let manager = manager.clone();
+            tokio::spawn(async move { manager.next_nonce(signer).unwrap() })
+        })
+        .collect();
+
+    let mut nonces = HashSet::new();
+    for handle in handles {
+        assert!(nonces.insert(handle.await.unwrap()));
+    }
+}
+
+#[test]
+fn test_nonce_is_tracked_per_signer() {
+    let manager = NonceManager::new();
+    let (a, b) = (Address::random(), Address::random());
+
+    for _ in 0..10_000 {
+        manager.next_nonce(a).unwrap();
+    }
+
+    let now = now_millis();
+    let nonce = manager.next_nonce(b).unwrap();
+
+    // `b` is not affected by the nonces handed out to `a`
+    assert!(nonce >= now && nonce < now + 1_000);
+    assert!(manager.last_nonce(a).unwrap() > nonce);
+}
+
+#[test]
+fn test_nonce_server_time_offset() {
+    let manager = NonceManager::new();
+    let signer = Address::random();
+
+    manager.sync_server_time(now_millis() + 60_000).unwrap();
+    assert!(manager.time_offset() > 59_000);
+
+    let nonce = manager.next_nonce(signer).unwrap();
+    assert!(nonce >= now_millis() + 59_000);
+
+    // moving the clock backwards never reuses a nonce
+    manager.set_time_offset(-60_000);
+    assert!(manager.next_nonce(signer).unwrap() > nonce);
+}
@@ -0,0 +1,271 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use ethers::{
+    core::rand::thread_rng,
+    signers::{LocalWallet, Signer},
+    types::Address,
+};
+use hyperliquid::{
+    types::{
+        exchange::request::{Action, CancelRequest},
+        info::request::Request as InfoRequest,
+        Chain, API,
+    },
+    Config, Error, Exchange, Hyperliquid, Info, MemoryTransport, RateLimitMode, RateLimiter,
+    Weighted, DEFAULT_ACTIONS_PER_ADDRESS,
+};
+use serde_json::json;
+
+#[test]
+fn test_info_weights() {
+    assert_eq!(InfoRequest::AllMids.weight(), 2);
+    assert_eq!(
+        InfoRequest::L2Book {
+            coin: "ETH".to_string()
+        }
+        .weight(),
+        2
+    );
+    assert_eq!(InfoRequest::Meta.weight(), 20);
+}
+
+#[tokio::test]
+async fn test_exchange_weights() {
+    let exchange: Exchange = Hyperliquid::new(Chain::ArbitrumTestnet);
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    let cancels = |n: u64| Action::Cancel {
+        cancels: (0..n).map(|oid| CancelRequest { asset: 4, oid }).collect(),
+    };
+
+    for (batch, weight) in [(1, 1), (39, 1), (40, 2), (100, 3)] {
+        let request = exchange
+            .sign_action(wallet.clone(), cancels(batch), None)
+            .await
+            .unwrap();
+
+        assert_eq!(request.weight(), weight);
+    }
+}
+
+#[tokio::test]
+async fn test_reject_when_exhausted() {
+    let limiter = RateLimiter::new(10, Duration::from_secs(60), RateLimitMode::Reject);
+
+    limiter.acquire(8).await.unwrap();
+
+    assert_eq!(limiter.remaining(), 2);
+    assert!(matches!(
+        limiter.acquire(3).await,
+        Err(Error::RateLimitExceeded {
+            weight: 3,
+            remaining: 2
+        })
+    ));
+    // a rejected request doesn't consume capacity
+    assert_eq!(limiter.remaining(), 2);
+
+    // can never fit
+    assert!(matches!(
+        limiter.acquire(11).await,
+        Err(Error::WeightExceedsCapacity {
+            weight: 11,
+            capacity: 10
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_queue_until_refilled() {
+    let limiter = RateLimiter::new(10, Duration::from_millis(200), RateLimitMode::Queue);
+
+    limiter.acquire(10).await.unwrap();
+
+    let start = Instant::now();
+
+    limiter.acquire(5).await.unwrap();
+
+    // half of the window is needed to refill 5 of 10
+    assert!(start.elapsed() >= Duration::from_millis(90));
+    assert!(limiter.remaining() < 5);
+}
+
+#[test]
+fn test_limiter_shared_by_config() {
+    let limiter = Arc::new(RateLimiter::default());
+
+    let mut config = Config::testnet();
+    config.set_rate_limiter(limiter.clone());
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let client = info.client.clone();
+
+    for shared in [
+        info.client.rate_limiter(),
+        exchange.client.rate_limiter(),
+        client.rate_limiter(),
+    ] {
+        assert!(Arc::ptr_eq(shared.unwrap(), &limiter));
+    }
+
+    assert_eq!(limiter.remaining(), 1200);
+
+    let default: Info = Hyperliquid::new(Chain::ArbitrumTestnet);
+
+    assert!(default.client.rate_limiter().is_none());
+}
+
+#[tokio::test]
+async fn test_reject_address_over_its_actions() {
+    let mut limiter = RateLimiter::new(1200, Duration::from_secs(60), RateLimitMode::Reject);
+    limiter.set_throttled_interval(Duration::from_secs(60));
+
+    let address = Address::random();
+
+    assert_eq!(
+        limiter.remaining_actions(address),
+        DEFAULT_ACTIONS_PER_ADDRESS
+    );
+
+    limiter.set_address_limit(address, 95, 100);
+    limiter.acquire_actions(address, 5).await.unwrap();
+
+    assert_eq!(limiter.remaining_actions(address), 0);
+
+    // once over its limit, an address may still send one request per interval
+    limiter.acquire_actions(address, 2).await.unwrap();
+
+    assert!(matches!(
+        limiter.acquire_actions(address, 1).await,
+        Err(Error::AddressRateLimitExceeded {
+            used: 102,
+            cap: 100,
+            ..
+        })
+    ));
+
+    // other addresses have their own limit
+    limiter.acquire_actions(Address::random(), 1).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_queue_throttled_address() {
+    let mut limiter = RateLimiter::new(1200, Duration::from_secs(60), RateLimitMode::Queue);
+    limiter.set_throttled_interval(Duration::from_millis(100));
+
+    let address = Address::random();
+    limiter.set_address_limit(address, 10, 10);
+
+    limiter.acquire_actions(address, 1).await.unwrap();
+
+    let start = Instant::now();
+
+    limiter.acquire_actions(address, 1).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(90));
+}
+
+#[tokio::test]
+async fn test_submit_counts_actions_of_account() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        API::Exchange,
+        "cancel",
+        json!({
+            "status": "ok",
+            "response": { "type": "cancel", "data": { "statuses": ["success", "success"] } }
+        }),
+    );
+
+    let limiter = Arc::new(RateLimiter::default());
+
+    let mut config = Config::testnet();
+    config.set_transport(Arc::new(transport));
+    config.set_rate_limiter(limiter.clone());
+
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let account = Address::random();
+
+    let action = Action::Cancel {
+        cancels: (0..2).map(|oid| CancelRequest { asset: 4, oid }).collect(),
+    };
+    let request = exchange
+        .sign_action(wallet.clone(), action, None)
+        .await
+        .unwrap();
+
+    exchange
+        .submit_for_account(&request, account)
+        .await
+        .unwrap();
+
+    // a batch counts one action per cancel
+    assert_eq!(
+        limiter.remaining_actions(account),
+        DEFAULT_ACTIONS_PER_ADDRESS - 2
+    );
+
+    assert_eq!(
+        limiter.remaining_actions(wallet.address()),
+        DEFAULT_ACTIONS_PER_ADDRESS
+    );
+}
+
+#[tokio::test]
+async fn test_submit_counts_actions_of_signer_by_default() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        API::Exchange,
+        "cancel",
+        json!({
+            "status": "ok",
+            "response": { "type": "cancel", "data": { "statuses": ["success"] } }
+        }),
+    );
+
+    let mut limiter = RateLimiter::new(1200, Duration::from_secs(60), RateLimitMode::Reject);
+    limiter.set_throttled_interval(Duration::from_secs(60));
+    let limiter = Arc::new(limiter);
+
+    let mut config = Config::testnet();
+    config.set_transport(Arc::new(transport));
+    config.set_rate_limiter(limiter.clone());
+
+    // no account set on the exchange
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+    limiter.set_address_limit(wallet.address(), 9, 10);
+
+    let action = || Action::Cancel {
+        cancels: vec![CancelRequest { asset: 4, oid: 1 }],
+    };
+
+    let request = exchange
+        .sign_action(wallet.clone(), action(), None)
+        .await
+        .unwrap();
+    exchange.submit(&request).await.unwrap();
+
+    assert_eq!(limiter.remaining_actions(wallet.address()), 0);
+
+    let request = exchange
+        .sign_action(wallet.clone(), action(), None)
+        .await
+        .unwrap();
+    exchange.submit(&request).await.unwrap();
+
+    let request = exchange
+        .sign_action(wallet.clone(), action(), None)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        exchange.submit(&request).await,
+        Err(Error::AddressRateLimitExceeded { address, .. }) if address == wallet.address()
+    ));
+}
@@ -0,0 +1,241 @@
+use std::{sync::Arc, time::Duration};
+
+use ethers::{
+    core::rand::thread_rng,
+    signers::{LocalWallet, Signer},
+};
+use hyperliquid::{
+    resubmission,
+    types::{
+        exchange::request::{
+            Action, CancelRequest, Grouping, Limit, OrderRequest, OrderType, Tif, UsdSend,
+        },
+        HyperliquidChain,
+    },
+    utils::{parse_price, parse_size},
+    Error, Exchange, Resubmission, RetryPolicy,
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use common::Server;
+
+mod common;
+
+fn fail_exchange(info: Value) -> impl Fn(&str) -> Option<(u16, String)> {
+    // exchange requests fail with a gateway error, which may come after the action was executed
+    move |path| match path {
+        "/info" => Some((200, info.to_string())),
+        _ => Some((502, "Bad Gateway".to_string())),
+    }
+}
+
+fn exchange(server: &Server) -> Exchange {
+    let mut exchange = server.exchange();
+    exchange.set_retry_policy(Some(policy()));
+    exchange
+}
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        max_retries: 2,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        multiplier: 2.0,
+    }
+}
+
+fn order(cloid: Option<Uuid>) -> Action {
+    Action::Order {
+        orders: vec![OrderRequest {
+            asset: 4,
+            is_buy: true,
+            limit_px: parse_price(1800.0),
+            sz: parse_size(0.1, 4),
+            reduce_only: false,
+            order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+            cloid,
+        }],
+        grouping: Grouping::Na,
+        builder: None,
+    }
+}
+
+fn usd_send() -> Action {
+    Action::UsdSend(UsdSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        amount: "1".to_string(),
+        time: 1700000000000,
+    })
+}
+
+async fn submit(exchange: &Exchange, action: Action) -> Error {
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let account = wallet.address();
+    let request = exchange.sign_action(wallet, action, None).await.unwrap();
+
+    exchange
+        .submit_for_account(&request, account)
+        .await
+        .unwrap_err()
+}
+
+#[test]
+fn test_backoff() {
+    let policy = RetryPolicy {
+        max_retries: 5,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_millis(500),
+        multiplier: 2.0,
+    };
+
+    assert_eq!(policy.backoff(0), Duration::from_millis(100));
+    assert_eq!(policy.backoff(1), Duration::from_millis(200));
+    assert_eq!(policy.backoff(2), Duration::from_millis(400));
+    assert_eq!(policy.backoff(3), Duration::from_millis(500));
+}
+
+#[test]
+fn test_resubmission() {
+    let cloid = Uuid::new_v4();
+
+    assert_eq!(
+        resubmission(&order(Some(cloid))),
+        Resubmission::IfOrdersUnknown(vec![cloid])
+    );
+    assert_eq!(resubmission(&order(None)), Resubmission::Never);
+    assert_eq!(resubmission(&usd_send()), Resubmission::Never);
+    assert_eq!(
+        resubmission(&Action::Cancel {
+            cancels: vec![CancelRequest { asset: 4, oid: 1 }]
+        }),
+        Resubmission::Always
+    );
+}
+
+#[tokio::test]
+async fn test_retry_idempotent_action() {
+    let server = Server::start(fail_exchange(json!(null))).await;
+    let action = Action::Cancel {
+        cancels: vec![CancelRequest { asset: 4, oid: 1 }],
+    };
+
+    let error = submit(&exchange(&server), action).await;
+
+    assert!(error.is_transient());
+    assert_eq!(server.requests("/exchange"), 3);
+}
+
+#[tokio::test]
+async fn test_never_resubmit_usd_send() {
+    let server = Server::start(fail_exchange(json!(null))).await;
+
+    let error = submit(&exchange(&server), usd_send()).await;
+
+    assert!(error.is_transient());
+    assert_eq!(server.requests("/exchange"), 1);
+
+    submit(&exchange(&server), order(None)).await;
+
+    assert_eq!(server.requests("/exchange"), 2);
+}
+
+#[tokio::test]
+async fn test_resubmit_unknown_order() {
+    let server = Server::start(fail_exchange(json!({ "status": "unknownOid" }))).await;
+
+    submit(&exchange(&server), order(Some(Uuid::new_v4()))).await;
+
+    assert_eq!(server.requests("/exchange"), 3);
+    assert_eq!(server.requests("/info"), 2);
+}
+
+#[tokio::test]
+async fn test_order_without_account_is_not_resubmitted() {
+    let server = Server::start(fail_exchange(json!({ "status": "unknownOid" }))).await;
+    let exchange = exchange(&server);
+
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let request = exchange
+        .sign_action(wallet, order(Some(Uuid::new_v4())), None)
+        .await
+        .unwrap();
+
+    // the signer may be an agent wallet, whose orders belong to another account
+    let error = exchange.submit(&request).await.unwrap_err();
+
+    assert!(error.is_transient());
+    assert_eq!(server.requests("/exchange"), 1);
+    assert_eq!(server.requests("/info"), 0);
+}
+
+#[tokio::test]
+async fn test_failed_order_lookup_is_reported() {
+    let server = Server::start(|path| match path {
+        "/info" => Some((500, "down".to_string())),
+        _ => Some((502, "Bad Gateway".to_string())),
+    })
+    .await;
+
+    let error = submit(&exchange(&server), order(Some(Uuid::new_v4()))).await;
+
+    assert!(matches!(
+        error,
+        Error::SubmissionUnconfirmed(submission, lookup)
+            if submission.is_transient() && matches!(*lookup, Error::Http(500, _))
+    ));
+    assert_eq!(server.requests("/exchange"), 1);
+}
+
+#[tokio::test]
+async fn test_landed_order_is_not_resubmitted() {
+    let cloid = Uuid::new_v4();
+    let server = Server::start(fail_exchange(json!({
+        "status": "order",
+        "order": {
+            "order": {
+                "children": [],
+                "cloid": format!("0x{}", cloid.simple()),
+                "coin": "ETH",
+                "isPositionTpsl": false,
+                "isTrigger": false,
+                "limitPx": "1800.0",
+                "oid": 42,
+                "orderType": "Limit",
+                "origSz": "0.1",
+                "reduceOnly": false,
+                "side": "B",
+                "sz": "0.1",
+                "tif": "Gtc",
+                "timestamp": 1700000000000i64,
+                "triggerCondition": "N/A",
+                "triggerPx": "0.0"
+            },
+            "status": "open",
+            "statusTimestamp": 1700000000000i64
+        }
+    })))
+    .await;
+
+    let error = submit(&exchange(&server), order(Some(cloid))).await;
+
+    assert!(matches!(error, Error::SubmissionLanded(oids) if oids == vec![42]));
+    assert_eq!(server.requests("/exchange"), 1);
+}
+
+#[tokio::test]
+async fn test_retry_without_policy() {
+    let server = Server::start(fail_exchange(json!(null))).await;
+    let mut exchange = exchange(&server);
+    exchange.set_retry_policy(None);
+
+    let action = Action::Cancel {
+        cancels: vec![CancelRequest { asset: 4, oid: 1 }],
+    };
+
+    submit(&exchange, action).await;
+
+    assert_eq!(server.requests("/exchange"), 1);
+}
@@ -0,0 +1,125 @@
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use ethers::{
+    core::rand::thread_rng,
+    signers::{LocalWallet, Signer, WalletError},
+    types::{
+        transaction::{eip2718::TypedTransaction, eip712::Eip712},
+        Address, Signature,
+    },
+};
+use hyperliquid::{
+    types::{
+        exchange::request::{Limit, OrderRequest, OrderType, Tif},
+        Chain,
+    },
+    Config, Error, Exchange, Hyperliquid,
+};
+
+/// Signer which isn't a `LocalWallet`, e.g. standing for a hardware or remote signer, recording
+/// the signer recovered from each typed data signature it makes
+#[derive(Debug)]
+struct RecordingSigner {
+    wallet: LocalWallet,
+    recovered: Mutex<Vec<Address>>,
+}
+
+#[async_trait]
+impl Signer for RecordingSigner {
+    type Error = WalletError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        self.wallet.sign_message(message).await
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        self.wallet.sign_transaction(tx).await
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        let signature = self.wallet.sign_typed_data(payload).await?;
+
+        let hash = payload
+            .encode_eip712()
+            .map_err(|e| WalletError::Eip712Error(e.to_string()))?;
+        self.recovered
+            .lock()
+            .unwrap()
+            .push(signature.recover(hash).unwrap());
+
+        Ok(signature)
+    }
+
+    fn address(&self) -> Address {
+        self.wallet.address()
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.wallet.chain_id()
+    }
+
+    fn with_chain_id<T: Into<u64>>(self, chain_id: T) -> Self {
+        Self {
+            wallet: self.wallet.with_chain_id(chain_id),
+            recovered: self.recovered,
+        }
+    }
+}
+
+/// Exchange sending requests to a closed local port, so that nothing is submitted
+fn offline_exchange() -> Exchange {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    let mut config = Config::local();
+    config.set_rest_endpoint(address);
+
+    Exchange::new_with_config(Chain::ArbitrumTestnet, &config)
+}
+
+#[tokio::test]
+async fn test_custom_signer() {
+    let exchange = offline_exchange();
+    let signer = Arc::new(RecordingSigner {
+        wallet: LocalWallet::new(&mut thread_rng()),
+        recovered: Mutex::new(vec![]),
+    });
+
+    let order = OrderRequest {
+        asset: 4,
+        is_buy: true,
+        limit_px: "1800".to_string(),
+        sz: "0.1".to_string(),
+        reduce_only: false,
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid: None,
+    };
+
+    // an L1 action and a user signed action, both signed before failing to be sent
+    let order = exchange
+        .place_order(signer.clone(), vec![order], None)
+        .await;
+    let transfer = exchange
+        .usdc_transfer(signer.clone(), Address::random(), "1".to_string())
+        .await;
+
+    for result in [order, transfer] {
+        assert!(matches!(result, Err(Error::Reqwest(e)) if e.is_connect()));
+    }
+
+    assert_eq!(
+        *signer.recovered.lock().unwrap(),
+        vec![signer.address(), signer.address()]
+    );
+}
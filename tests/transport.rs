@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use ethers::{core::rand::thread_rng, signers::LocalWallet, types::Address};
+use hyperliquid::{
+    types::{
+        exchange::request::{Builder, Limit, ModifyRequest, OrderRequest, OrderType, Tif},
+        Chain, API,
+    },
+    utils::{parse_price, parse_size},
+    Config, Error, Exchange, Hyperliquid, Info, MemoryTransport,
+};
+use serde_json::json;
+
+fn config(transport: &MemoryTransport) -> Config {
+    let mut config = Config::testnet();
+    config.set_transport(Arc::new(transport.clone()));
+    config
+}
+
+fn order() -> OrderRequest {
+    OrderRequest {
+        asset: 4,
+        is_buy: true,
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
+        reduce_only: false,
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid: None,
+    }
+}
+
+fn builder() -> Builder {
+    Builder {
+        builder: Address::repeat_byte(0x11),
+        fee: 10,
+    }
+}
+
+#[tokio::test]
+async fn test_info_canned_response() {
+    let transport = MemoryTransport::new();
+    transport.respond(API::Info, "allMids", json!({ "ETH": "1800.5" }));
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config(&transport));
+
+    let mids = info.mids().await.unwrap();
+
+    assert_eq!(mids["ETH"], "1800.5");
+    assert_eq!(
+        transport.sent(),
+        vec![(API::Info, json!({ "type": "allMids" }))]
+    );
+}
+
+#[tokio::test]
+async fn test_exchange_records_orders() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        API::Exchange,
+        "order",
+        json!({
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": { "statuses": [{ "resting": { "oid": 42 } }] }
+            }
+        }),
+    );
+
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config(&transport));
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    let results = exchange
+        .place_orders(wallet, vec![order()], None)
+        .await
+        .unwrap();
+
+    assert_eq!(results[0].oid(), Some(42));
+
+    let sent = transport.sent_of(API::Exchange, "order");
+
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0]["action"]["orders"][0]["a"], 4);
+    assert_eq!(sent[0]["action"]["orders"][0]["b"], true);
+    assert!(sent[0]["nonce"].is_u64());
+    assert!(sent[0].get("signature").is_some());
+}
+
+#[tokio::test]
+async fn test_exchange_records_builder() {
+    let transport = MemoryTransport::new();
+    transport.respond(
+        API::Exchange,
+        "order",
+        json!({
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": { "statuses": [{ "resting": { "oid": 42 } }] }
+            }
+        }),
+    );
+
+    let mut exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config(&transport));
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    exchange.set_builder(Some(builder()));
+    exchange
+        .place_orders(wallet.clone(), vec![order()], None)
+        .await
+        .unwrap();
+    exchange.set_builder(None);
+    exchange
+        .place_orders(wallet, vec![order()], None)
+        .await
+        .unwrap();
+
+    let sent = transport.sent_of(API::Exchange, "order");
+
+    assert_eq!(
+        sent[0]["action"]["builder"],
+        json!({ "b": "0x1111111111111111111111111111111111111111", "f": 10 })
+    );
+    assert!(sent[1]["action"].get("builder").is_none());
+}
+
+#[tokio::test]
+async fn test_exchange_records_builder_of_modifies() {
+    let transport = MemoryTransport::new();
+    for r#type in ["modify", "batchModify"] {
+        transport.respond(
+            API::Exchange,
+            r#type,
+            json!({ "status": "ok", "response": { "type": "default" } }),
+        );
+    }
+
+    let mut exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config(&transport));
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+    let modify = ModifyRequest {
+        oid: 42,
+        order: order(),
+    };
+
+    exchange.set_builder(Some(builder()));
+    exchange
+        .modify_order(wallet.clone(), modify.clone(), None)
+        .await
+        .unwrap();
+    exchange
+        .batch_modify_orders(wallet, vec![modify], None)
+        .await
+        .unwrap();
+
+    let builder = json!({ "b": "0x1111111111111111111111111111111111111111", "f": 10 });
+    let modify = &transport.sent_of(API::Exchange, "modify")[0]["action"];
+    assert_eq!(modify["oid"], 42);
+    assert_eq!(modify["builder"], builder);
+    let batch_modify = &transport.sent_of(API::Exchange, "batchModify")[0]["action"];
+    assert_eq!(batch_modify["modifies"][0]["oid"], 42);
+    assert_eq!(batch_modify["builder"], builder);
+}
+
+#[tokio::test]
+async fn test_missing_response() {
+    let transport = MemoryTransport::new();
+
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config(&transport));
+
+    assert!(matches!(info.mids().await, Err(Error::Http(404, _))));
+    assert_eq!(transport.sent_of(API::Info, "allMids").len(), 1);
+
+    transport.clear_sent();
+
+    assert!(transport.sent().is_empty());
+}
+
+#[tokio::test]
+async fn test_status_and_failures() {
+    let transport = MemoryTransport::new();
+    transport.respond_with(API::Info, "allMids", 429, String::new());
+    transport.respond(
+        API::Exchange,
+        "order",
+        json!({ "status": "err", "response": "Invalid nonce: duplicate nonce" }),
+    );
+
+    let config = config(&transport);
+    let info = Info::new_with_config(Chain::ArbitrumTestnet, &config);
+    let exchange = Exchange::new_with_config(Chain::ArbitrumTestnet, &config);
+    let wallet = Arc::new(LocalWallet::new(&mut thread_rng()));
+
+    assert!(matches!(info.mids().await, Err(Error::RateLimited(_))));
+    assert!(matches!(
+        exchange.place_order(wallet, vec![order()], None).await,
+        Err(Error::InvalidNonce(_))
+    ));
+
+    // info and exchange share the transport
+    assert_eq!(transport.sent().len(), 2);
+}
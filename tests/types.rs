@@ -0,0 +1,779 @@
+use ethers::{
+    types::{transaction::eip712::Eip712, Address},
+    utils::keccak256,
+};
+use hyperliquid::{
+    types::{
+        exchange::{
+            request::{
+                Action, ApproveBuilderFee, Builder, CDeposit, CancelFilter, CancelRequest,
+                ConvertToMultiSigUser, Grouping, Limit, ModifyRequest, MultiSigEnvelope,
+                MultiSigSigners, OrderRequest, OrderType, SendMultiSig, SpotSend, Tif,
+                TokenDelegate, UsdClassTransfer, UsdSend,
+            },
+            response::{OrderErrorKind, OrderPlacementStatus, Response, Status as ExchangeStatus},
+        },
+        info::{
+            self,
+            response::{
+                DelegatorDelta, DelegatorHistory, DelegatorReward, DelegatorSummary,
+                FrontendOpenOrders, RewardSource, TwapHistory, TwapStatus, UserVaultEquity,
+                VaultDetails,
+            },
+        },
+        websocket::{request::Subscription, response::Response as WsResponse},
+        HyperliquidChain, Side,
+    },
+    utils::{parse_price, parse_size},
+    Error,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn test_usd_class_transfer() {
+    let payload = UsdClassTransfer {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        amount: "1".to_string(),
+        to_perp: true,
+        nonce: 1700000000000,
+    };
+
+    assert_eq!(
+        UsdClassTransfer::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:UsdClassTransfer(string hyperliquidChain,string amount,bool toPerp,uint64 nonce)"
+        )
+    );
+
+    let action = Action::UsdClassTransfer(payload);
+
+    assert_eq!(action.nonce(), Some(1700000000000));
+    assert_eq!(
+        serde_json::to_value(action).unwrap(),
+        json!({
+            "type": "usdClassTransfer",
+            "signatureChainId": "0x66eee",
+            "hyperliquidChain": "Testnet",
+            "amount": "1",
+            "toPerp": true,
+            "nonce": 1700000000000u64,
+        })
+    );
+}
+
+#[test]
+fn test_token_delegate() {
+    let payload = TokenDelegate {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        validator: "0x5ac99df645f3414876c816caa18b2d234024b487"
+            .parse()
+            .unwrap(),
+        wei: 100_000_000,
+        is_undelegate: false,
+        nonce: 1700000000000,
+    };
+
+    assert_eq!(
+        TokenDelegate::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:TokenDelegate(string hyperliquidChain,address validator,uint64 wei,bool isUndelegate,uint64 nonce)"
+        )
+    );
+    assert_eq!(
+        CDeposit::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:CDeposit(string hyperliquidChain,uint64 wei,uint64 nonce)"
+        )
+    );
+
+    assert_eq!(
+        serde_json::to_value(Action::TokenDelegate(payload)).unwrap(),
+        json!({
+            "type": "tokenDelegate",
+            "signatureChainId": "0x66eee",
+            "hyperliquidChain": "Testnet",
+            "validator": "0x5ac99df645f3414876c816caa18b2d234024b487",
+            "wei": 100_000_000,
+            "isUndelegate": false,
+            "nonce": 1700000000000u64,
+        })
+    );
+}
+
+#[test]
+fn test_staking_responses() {
+    let summary: DelegatorSummary = serde_json::from_value(json!({
+        "delegated": "12060.16529862",
+        "undelegated": "0.0",
+        "totalPendingWithdrawal": "0.0",
+        "nPendingWithdrawals": 0,
+    }))
+    .unwrap();
+
+    assert_eq!(summary.delegated.to_string(), "12060.16529862");
+
+    let history: Vec<DelegatorHistory> = serde_json::from_value(json!([
+        {
+            "time": 1735380381000u64,
+            "hash": "0x55492465cb523f90815a041a226ba90147008d4b221a24ae8dc35a0dbede4ea4",
+            "delta": {
+                "delegate": {
+                    "validator": "0x5ac99df645f3414876c816caa18b2d234024b487",
+                    "amount": "10000.0",
+                    "isUndelegate": false,
+                }
+            }
+        },
+        {
+            "time": 1735380301000u64,
+            "hash": "0x3bdea9bba7d2ad9bd86d04102fc3fb0104ce4f8fc21d82e2a6306a79fa4e84ec",
+            "delta": { "cDeposit": { "amount": "10000.0" } }
+        }
+    ]))
+    .unwrap();
+
+    assert!(matches!(
+        history[0].delta,
+        DelegatorDelta::Delegate {
+            is_undelegate: false,
+            ..
+        }
+    ));
+    assert!(matches!(history[1].delta, DelegatorDelta::CDeposit { .. }));
+
+    let rewards: Vec<DelegatorReward> = serde_json::from_value(json!([
+        { "time": 1736726400073u64, "source": "delegation", "totalAmount": "0.73117184" },
+        { "time": 1736726400073u64, "source": "commission", "totalAmount": "130.76445876" },
+    ]))
+    .unwrap();
+
+    assert!(matches!(rewards[1].source, RewardSource::Commission));
+}
+
+#[test]
+fn test_multi_sig_types() {
+    assert_eq!(
+        MultiSigEnvelope::<UsdSend>::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:UsdSend(string hyperliquidChain,address payloadMultiSigUser,address outerSigner,string destination,string amount,uint64 time)"
+        )
+    );
+    assert_eq!(
+        SendMultiSig::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:SendMultiSig(string hyperliquidChain,bytes32 multiSigActionHash,uint64 nonce)"
+        )
+    );
+    assert_eq!(
+        ConvertToMultiSigUser::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:ConvertToMultiSigUser(string hyperliquidChain,string signers,uint64 nonce)"
+        )
+    );
+
+    let signers = MultiSigSigners {
+        authorized_users: vec!["0x0d1d9635d0640821d15e323ac8adadfa9c111414"
+            .parse()
+            .unwrap()],
+        threshold: 1,
+    };
+
+    assert_eq!(
+        serde_json::to_string(&signers).unwrap(),
+        r#"{"authorizedUsers":["0x0d1d9635d0640821d15e323ac8adadfa9c111414"],"threshold":1}"#
+    );
+}
+
+#[test]
+fn test_as_user_signed() {
+    let usd_send = || UsdSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        amount: "1".to_string(),
+        time: 1700000000000,
+    };
+    let multi_sig_user: Address = "0x0d1d9635d0640821d15e323ac8adadfa9c111414"
+        .parse()
+        .unwrap();
+    let outer_signer = Address::repeat_byte(0x11);
+
+    let action = Action::UsdSend(usd_send());
+    let payload = action.as_user_signed().unwrap();
+
+    assert_eq!(payload.nonce(), 1700000000000);
+    assert_eq!(action.nonce(), Some(1700000000000));
+    assert_eq!(
+        payload.typed_data().unwrap().encode_eip712().unwrap(),
+        usd_send().encode_eip712().unwrap()
+    );
+    assert_eq!(
+        payload
+            .multi_sig_typed_data(multi_sig_user, outer_signer)
+            .unwrap()
+            .encode_eip712()
+            .unwrap(),
+        MultiSigEnvelope {
+            payload: &usd_send(),
+            multi_sig_user,
+            outer_signer,
+        }
+        .encode_eip712()
+        .unwrap()
+    );
+
+    let order = Action::Order {
+        orders: vec![],
+        grouping: Grouping::Na,
+        builder: None,
+    };
+
+    assert!(order.as_user_signed().is_none());
+}
+
+#[test]
+fn test_spot_send() {
+    let payload = SpotSend {
+        signature_chain_id: 421614.into(),
+        hyperliquid_chain: HyperliquidChain::Testnet,
+        destination: "0x0D1d9635D0640821d15e323ac8AdADfA9c111414".to_string(),
+        token: "PURR:0xc1fb593aeffbeb02f85e0308e9956a90".to_string(),
+        amount: "1".to_string(),
+        time: 1700000000000,
+    };
+
+    assert_eq!(
+        SpotSend::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:SpotSend(string hyperliquidChain,string destination,string token,string amount,uint64 time)"
+        )
+    );
+
+    let action = serde_json::to_value(Action::SpotSend(payload)).unwrap();
+
+    assert_eq!(
+        action,
+        serde_json::json!({
+            "type": "spotSend",
+            "signatureChainId": "0x66eee",
+            "hyperliquidChain": "Testnet",
+            "destination": "0x0D1d9635D0640821d15e323ac8AdADfA9c111414",
+            "token": "PURR:0xc1fb593aeffbeb02f85e0308e9956a90",
+            "amount": "1",
+            "time": 1700000000000u64,
+        })
+    );
+}
+
+fn order(cloid: Option<Uuid>) -> OrderRequest {
+    OrderRequest {
+        asset: 4,
+        is_buy: true,
+        limit_px: parse_price(1800.0),
+        sz: parse_size(0.1, 4),
+        reduce_only: false,
+        order_type: OrderType::Limit(Limit { tif: Tif::Gtc }),
+        cloid,
+    }
+}
+
+#[test]
+fn test_order_results() {
+    let response: Response = serde_json::from_value(serde_json::json!({
+        "status": "ok",
+        "response": {
+            "type": "order",
+            "data": {
+                "statuses": [
+                    { "resting": { "oid": 77738308 } },
+                    { "filled": { "totalSz": "0.02", "avgPx": "1891.4", "oid": 77747314 } },
+                    { "error": "Order must have minimum value of $10." },
+                ]
+            }
+        }
+    }))
+    .unwrap();
+
+    let cloid = Uuid::new_v4();
+    let orders = vec![order(None), order(Some(cloid)), order(None)];
+
+    let results = response.into_order_results(&orders).unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(matches!(
+        results[0].status,
+        OrderPlacementStatus::Resting(_)
+    ));
+    assert_eq!(results[0].oid(), Some(77738308));
+    assert_eq!(results[1].cloid, Some(cloid));
+    assert_eq!(results[1].oid(), Some(77747314));
+    assert!(results[2].is_err());
+    assert_eq!(
+        results[2].error().unwrap().kind,
+        OrderErrorKind::MinTradeNtl
+    );
+}
+
+#[test]
+fn test_order_results_rejected() {
+    let response: Response = serde_json::from_value(serde_json::json!({
+        "status": "err",
+        "response": "User or API Wallet 0x0000000000000000000000000000000000000001 does not exist."
+    }))
+    .unwrap();
+
+    assert!(matches!(
+        response.into_order_results(&[order(None)]),
+        Err(Error::ExchangeError(_))
+    ));
+}
+
+#[test]
+fn test_order_results_mismatch() {
+    let response: Response = serde_json::from_value(serde_json::json!({
+        "status": "ok",
+        "response": { "type": "order", "data": { "statuses": [{ "resting": { "oid": 1 } }] } }
+    }))
+    .unwrap();
+
+    assert!(matches!(
+        response.into_order_results(&[order(None), order(None)]),
+        Err(Error::UnexpectedResponse(_))
+    ));
+}
+
+#[test]
+fn test_vault_actions() {
+    let vault_address: Address = "0xdfc24b077bc1425ad1dea75bcb6f8158e10df303"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(Action::VaultTransfer {
+            vault_address,
+            is_deposit: true,
+            usd: 1_000_000,
+        })
+        .unwrap(),
+        json!({
+            "type": "vaultTransfer",
+            "vaultAddress": "0xdfc24b077bc1425ad1dea75bcb6f8158e10df303",
+            "isDeposit": true,
+            "usd": 1_000_000,
+        })
+    );
+
+    let action = Action::CreateVault {
+        name: "Vault".to_string(),
+        description: "Description".to_string(),
+        initial_usd: 100_000_000,
+        nonce: 1700000000000,
+    };
+
+    assert_eq!(action.nonce(), Some(1700000000000));
+    assert_eq!(
+        serde_json::to_value(action).unwrap(),
+        json!({
+            "type": "createVault",
+            "name": "Vault",
+            "description": "Description",
+            "initialUsd": 100_000_000,
+            "nonce": 1700000000000u64,
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(info::request::Request::VaultDetails {
+            vault_address,
+            user: None,
+        })
+        .unwrap(),
+        json!({
+            "type": "vaultDetails",
+            "vaultAddress": "0xdfc24b077bc1425ad1dea75bcb6f8158e10df303",
+        })
+    );
+}
+
+#[test]
+fn test_vault_responses() {
+    let equities: Vec<UserVaultEquity> = serde_json::from_value(json!([
+        {
+            "vaultAddress": "0xdfc24b077bc1425ad1dea75bcb6f8158e10df303",
+            "equity": "742500.082809",
+            "lockedUntilTimestamp": 1700000000000u64,
+        }
+    ]))
+    .unwrap();
+
+    assert_eq!(equities[0].equity.to_string(), "742500.082809");
+
+    let follower = json!({
+        "user": "0x005844b2ffb2e122cf4244be7dbcb4f84924907c",
+        "vaultEquity": "714491.71026243",
+        "pnl": "3203.43026143",
+        "allTimePnl": "79843.74476743",
+        "daysFollowing": 388,
+        "vaultEntryTime": 1667841372428u64,
+        "lockupUntil": 1668446172428u64,
+    });
+
+    let details: VaultDetails = serde_json::from_value(json!({
+        "name": "Test",
+        "vaultAddress": "0xdfc24b077bc1425ad1dea75bcb6f8158e10df303",
+        "leader": "0x677d831aef5328190852e24f13c46cac05f984e7",
+        "description": "This community-owned vault provides liquidity to Hyperliquid",
+        "portfolio": [["day", { "accountValueHistory": [], "pnlHistory": [], "vlm": "0.0" }]],
+        "apr": 0.36387129259090006,
+        "followerState": null,
+        "leaderFraction": 0.0007904828725729887,
+        "leaderCommission": 0,
+        "followers": [follower],
+        "maxDistributable": 94290568.52176307,
+        "maxWithdrawable": 742500.08281,
+        "isClosed": false,
+        "relationship": { "type": "parent", "data": { "childAddresses": [] } },
+        "allowDeposits": true,
+        "alwaysCloseOnWithdraw": false,
+    }))
+    .unwrap();
+
+    assert_eq!(details.followers.len(), 1);
+    assert_eq!(details.followers[0].days_following, 388);
+    assert!(details.follower_state.is_none());
+}
+
+#[test]
+fn test_position_tpsl_grouping() {
+    let action = serde_json::to_value(Action::Order {
+        orders: vec![order(None)],
+        grouping: Grouping::PositionTpsl,
+        builder: None,
+    })
+    .unwrap();
+
+    assert_eq!(action["grouping"], "positionTpsl");
+}
+
+#[test]
+fn test_builder_fee() {
+    let builder: Address = "0x8c967e73e7b15087c42a10d344cff4c96d877f1d"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        ApproveBuilderFee::type_hash().unwrap(),
+        keccak256(
+            "HyperliquidTransaction:ApproveBuilderFee(string hyperliquidChain,string maxFeeRate,address builder,uint64 nonce)"
+        )
+    );
+
+    let action = Action::Order {
+        orders: vec![order(None)],
+        grouping: Grouping::Na,
+        builder: Some(Builder { builder, fee: 10 }),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&action).unwrap()["builder"],
+        json!({ "b": "0x8c967e73e7b15087c42a10d344cff4c96d877f1d", "f": 10 })
+    );
+
+    let without_builder = Action::Order {
+        orders: vec![order(None)],
+        grouping: Grouping::Na,
+        builder: None,
+    };
+
+    assert!(serde_json::to_value(&without_builder)
+        .unwrap()
+        .get("builder")
+        .is_none());
+
+    // the builder is part of the signed payload
+    assert_ne!(
+        action.connection_id(None, 1700000000000, None).unwrap(),
+        without_builder
+            .connection_id(None, 1700000000000, None)
+            .unwrap()
+    );
+
+    let modify = Action::BatchModify {
+        modifies: vec![ModifyRequest {
+            oid: 1,
+            order: order(None),
+        }],
+        builder: Some(Builder { builder, fee: 10 }),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&modify).unwrap()["builder"],
+        json!({ "b": "0x8c967e73e7b15087c42a10d344cff4c96d877f1d", "f": 10 })
+    );
+}
+
+#[test]
+fn test_connection_id_expires_after() {
+    let action = Action::TwapCancel {
+        asset: 4,
+        twap_id: 1,
+    };
+    let nonce: u64 = 1700000000000;
+    let expires_after: u64 = 1700000060000;
+
+    let mut encoded = rmp_serde::to_vec_named(&action).unwrap();
+    encoded.extend(nonce.to_be_bytes());
+    encoded.push(0);
+
+    assert_eq!(
+        action.connection_id(None, nonce, None).unwrap(),
+        keccak256(&encoded).into()
+    );
+
+    encoded.push(0);
+    encoded.extend(expires_after.to_be_bytes());
+
+    assert_eq!(
+        action
+            .connection_id(None, nonce, Some(expires_after))
+            .unwrap(),
+        keccak256(&encoded).into()
+    );
+}
+
+#[test]
+fn test_twap_cancel() {
+    let action = Action::TwapCancel {
+        asset: 4,
+        twap_id: 12345,
+    };
+
+    assert_eq!(
+        serde_json::to_value(&action).unwrap(),
+        json!({ "type": "twapCancel", "a": 4, "t": 12345 })
+    );
+
+    let response: Response = serde_json::from_value(json!({
+        "status": "ok",
+        "response": { "type": "twapCancel", "data": { "status": "success" } }
+    }))
+    .unwrap();
+
+    assert!(matches!(
+        response.into_statuses().unwrap()[..],
+        [ExchangeStatus::Success]
+    ));
+}
+
+fn twap_state() -> serde_json::Value {
+    json!({
+        "coin": "ETH",
+        "user": "0x0d1d9635d0640821d15e323ac8adadfa9c111414",
+        "side": "B",
+        "sz": "1.0",
+        "executedSz": "0.25",
+        "executedNtl": "475.5",
+        "minutes": 30,
+        "reduceOnly": false,
+        "randomize": true,
+        "timestamp": 1700000000000u64,
+    })
+}
+
+#[test]
+fn test_twap_history() {
+    let history: Vec<TwapHistory> = serde_json::from_value(json!([
+        {
+            "time": 1700000000,
+            "state": twap_state(),
+            "status": { "status": "activated" },
+            "twapId": 12345,
+        },
+        {
+            "time": 1700001800,
+            "state": twap_state(),
+            "status": { "status": "error", "description": "Insufficient margin" },
+        }
+    ]))
+    .unwrap();
+
+    assert!(matches!(history[0].status, TwapStatus::Activated));
+    assert_eq!(history[0].twap_id, Some(12345));
+    assert_eq!(history[0].state.executed_sz.to_string(), "0.25");
+    assert!(matches!(
+        &history[1].status,
+        TwapStatus::Error { description } if description == "Insufficient margin"
+    ));
+}
+
+#[test]
+fn test_twap_websocket() {
+    let fill = json!({
+        "coin": "ETH",
+        "px": "1902.0",
+        "sz": "0.25",
+        "side": "B",
+        "time": 1700000060000u64,
+        "startPosition": "0.0",
+        "dir": "Open Long",
+        "closedPnl": "0.0",
+        "hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+        "oid": 1,
+        "crossed": true,
+        "fee": "0.12",
+        "tid": 1,
+    });
+
+    let response: WsResponse = serde_json::from_value(json!({
+        "channel": "userTwapSliceFills",
+        "data": {
+            "isSnapshot": true,
+            "user": "0x0d1d9635d0640821d15e323ac8adadfa9c111414",
+            "twapSliceFills": [{ "fill": fill, "twapId": 12345 }],
+        }
+    }))
+    .unwrap();
+
+    match response {
+        WsResponse::UserTwapSliceFills(fills) => {
+            assert!(fills.is_snapshot);
+            assert_eq!(fills.twap_slice_fills[0].twap_id, 12345);
+        }
+        _ => panic!("Expected twap slice fills"),
+    }
+
+    let response: WsResponse = serde_json::from_value(json!({
+        "channel": "twapStates",
+        "data": {
+            "user": "0x0d1d9635d0640821d15e323ac8adadfa9c111414",
+            "states": [[12345, twap_state()]],
+        }
+    }))
+    .unwrap();
+
+    match response {
+        WsResponse::TwapStates(states) => assert_eq!(states.states[0].0, 12345),
+        _ => panic!("Expected twap states"),
+    }
+
+    assert_eq!(
+        serde_json::to_value(Subscription::UserTwapHistory {
+            user: "0x0d1d9635d0640821d15e323ac8adadfa9c111414"
+                .parse()
+                .unwrap()
+        })
+        .unwrap(),
+        json!({
+            "type": "userTwapHistory",
+            "user": "0x0d1d9635d0640821d15e323ac8adadfa9c111414",
+        })
+    );
+}
+
+fn open_order(
+    coin: &str,
+    side: &str,
+    reduce_only: bool,
+    cloid: Option<&str>,
+) -> FrontendOpenOrders {
+    serde_json::from_value(json!({
+        "coin": coin,
+        "isPositionTpsl": false,
+        "isTrigger": false,
+        "limitPx": "1800.0",
+        "oid": 1,
+        "orderType": "Limit",
+        "origSz": "0.1",
+        "reduceOnly": reduce_only,
+        "side": side,
+        "sz": "0.1",
+        "timestamp": 1700000000000u64,
+        "triggerCondition": "N/A",
+        "triggerPx": "0.0",
+        "cloid": cloid,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_cancel_filter() {
+    let eth_bid = open_order(
+        "ETH",
+        "B",
+        false,
+        Some("0xabcd0000000000000000000000000001"),
+    );
+    let btc_ask = open_order("BTC", "A", true, None);
+
+    let all = CancelFilter::default();
+    assert!(all.matches(&eth_bid));
+    assert!(all.matches(&btc_ask));
+
+    let eth = CancelFilter {
+        coin: Some("ETH".to_string()),
+        ..Default::default()
+    };
+    assert!(eth.matches(&eth_bid));
+    assert!(!eth.matches(&btc_ask));
+
+    let asks = CancelFilter {
+        side: Some(Side::A),
+        reduce_only: Some(true),
+        ..Default::default()
+    };
+    assert!(!asks.matches(&eth_bid));
+    assert!(asks.matches(&btc_ask));
+
+    let tagged = CancelFilter {
+        cloid_prefix: Some("0xABCD".to_string()),
+        ..Default::default()
+    };
+    assert!(tagged.matches(&eth_bid));
+    assert!(!tagged.matches(&btc_ask));
+}
+
+#[test]
+fn test_cancel_results() {
+    let response: Response = serde_json::from_value(json!({
+        "status": "ok",
+        "response": {
+            "type": "cancel",
+            "data": {
+                "statuses": [
+                    "success",
+                    { "error": "Order was never placed, already canceled, or filled." },
+                ]
+            }
+        }
+    }))
+    .unwrap();
+
+    let cancels = [
+        CancelRequest { asset: 4, oid: 1 },
+        CancelRequest { asset: 4, oid: 2 },
+    ];
+
+    let results = response.into_cancel_results(&cancels).unwrap();
+
+    assert!(!results[0].is_err());
+    assert_eq!(results[1].oid, 2);
+    assert!(results[1].is_err());
+}
+
+#[test]
+fn test_schedule_cancel_unset() {
+    assert_eq!(
+        serde_json::to_value(Action::ScheduleCancel { time: None }).unwrap(),
+        json!({ "type": "scheduleCancel" })
+    );
+    assert_eq!(
+        serde_json::to_value(Action::ScheduleCancel {
+            time: Some(1700000000000)
+        })
+        .unwrap(),
+        json!({ "type": "scheduleCancel", "time": 1700000000000u64 })
+    );
+}
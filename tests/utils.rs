@@ -1,4 +1,8 @@
-use hyperliquid::utils::{parse_price, parse_size};
+use hyperliquid::{
+    utils::{parse_price, parse_size, round_price, round_size, RoundingMode},
+    AssetKind,
+};
+use proptest::prelude::*;
 
 #[test]
 fn test_parse_price() {
@@ -7,6 +11,9 @@ fn test_parse_price() {
     assert_eq!(parse_price(0.001234), "0.001234");
     assert_eq!(parse_price(0.0012345), "0.001234");
     assert_eq!(parse_price(1.2345678), "1.2345");
+    assert_eq!(parse_price(12345.6), "12345");
+    assert_eq!(parse_price(104123.45), "104123");
+    assert_eq!(parse_price(-1.0), "0");
 }
 
 #[test]
@@ -19,3 +26,123 @@ fn test_parse_size() {
 
     assert_eq!(parse_size(1.001, 5), "1.001");
 }
+
+#[test]
+fn test_round_price() {
+    use AssetKind::{Perp, Spot};
+    use RoundingMode::{Down, Nearest, Up};
+
+    assert_eq!(round_price(104123.45, Perp, 5, Down), "104123");
+    assert_eq!(round_price(104123.45, Perp, 5, Up), "104124");
+    assert_eq!(round_price(2805.43, Perp, 4, Down), "2805.4");
+    assert_eq!(round_price(2805.43, Perp, 4, Up), "2805.5");
+    assert_eq!(round_price(2805.45, Perp, 4, Nearest), "2805.5");
+    assert_eq!(round_price(1234.5, Perp, 4, Down), "1234.5");
+    assert_eq!(round_price(1234.5, Perp, 4, Up), "1234.5");
+
+    // max decimals is 6 - szDecimals for perps and 8 - szDecimals for spot
+    assert_eq!(round_price(0.12345678, Perp, 0, Down), "0.12345");
+    assert_eq!(round_price(0.00012345678, Perp, 0, Down), "0.000123");
+    assert_eq!(round_price(0.00012345678, Perp, 3, Down), "0");
+    assert_eq!(round_price(0.00012345678, Perp, 3, Up), "0.001");
+    assert_eq!(round_price(0.00012345678, Spot, 0, Down), "0.00012345");
+    assert_eq!(round_price(0.00012345678, Spot, 2, Nearest), "0.000123");
+
+    assert_eq!(round_price(9.99996, Perp, 0, Up), "10");
+    assert_eq!(round_price(0.0, Perp, 0, Up), "0");
+    assert_eq!(round_price(f64::NAN, Perp, 0, Up), "0");
+}
+
+#[test]
+fn test_round_size() {
+    assert_eq!(round_size(0.0331, 4, RoundingMode::Down), "0.0331");
+    assert_eq!(round_size(0.03319, 4, RoundingMode::Down), "0.0331");
+    assert_eq!(round_size(0.03311, 4, RoundingMode::Up), "0.0332");
+    assert_eq!(round_size(12.5, 0, RoundingMode::Nearest), "13");
+    assert_eq!(round_size(-1.0, 2, RoundingMode::Nearest), "0");
+}
+
+fn kind() -> impl Strategy<Value = AssetKind> {
+    prop_oneof![Just(AssetKind::Perp), Just(AssetKind::Spot)]
+}
+
+fn mode() -> impl Strategy<Value = RoundingMode> {
+    prop_oneof![
+        Just(RoundingMode::Down),
+        Just(RoundingMode::Up),
+        Just(RoundingMode::Nearest)
+    ]
+}
+
+fn decimals(value: &str) -> u32 {
+    value.split_once('.').map_or(0, |(_, d)| d.len() as u32)
+}
+
+fn sig_figs(value: &str) -> usize {
+    value
+        .replace('.', "")
+        .trim_start_matches('0')
+        .trim_end_matches('0')
+        .len()
+}
+
+proptest! {
+    #[test]
+    fn prop_round_price_is_valid_tick(
+        px in 1e-6f64..1e7,
+        kind in kind(),
+        sz_decimals in 0u32..6,
+        mode in mode(),
+    ) {
+        let rounded = round_price(px, kind, sz_decimals, mode);
+        let max_decimals = match kind {
+            AssetKind::Perp => 6 - sz_decimals,
+            AssetKind::Spot => 8 - sz_decimals,
+        };
+
+        prop_assert!(rounded.parse::<f64>().is_ok());
+        prop_assert!(decimals(&rounded) <= max_decimals);
+        prop_assert!(!rounded.contains('.') || sig_figs(&rounded) <= 5);
+    }
+
+    #[test]
+    fn prop_round_price_respects_mode(
+        px in 1e-6f64..1e7,
+        kind in kind(),
+        sz_decimals in 0u32..6,
+    ) {
+        let down: f64 = round_price(px, kind, sz_decimals, RoundingMode::Down).parse().unwrap();
+        let nearest: f64 = round_price(px, kind, sz_decimals, RoundingMode::Nearest).parse().unwrap();
+        let up: f64 = round_price(px, kind, sz_decimals, RoundingMode::Up).parse().unwrap();
+
+        let eps = px * 1e-12;
+        prop_assert!(down <= px + eps);
+        prop_assert!(up >= px - eps);
+        prop_assert!(down <= nearest && nearest <= up);
+    }
+
+    #[test]
+    fn prop_round_price_is_idempotent(
+        px in 1e-6f64..1e7,
+        kind in kind(),
+        sz_decimals in 0u32..6,
+        mode in mode(),
+    ) {
+        let rounded = round_price(px, kind, sz_decimals, mode);
+        let again = round_price(rounded.parse().unwrap(), kind, sz_decimals, mode);
+
+        prop_assert_eq!(rounded, again);
+    }
+
+    #[test]
+    fn prop_round_size_respects_sz_decimals(
+        sz in 1e-6f64..1e7,
+        sz_decimals in 0u32..9,
+        mode in mode(),
+    ) {
+        let rounded = round_size(sz, sz_decimals, mode);
+
+        prop_assert!(decimals(&rounded) <= sz_decimals);
+        prop_assert_eq!(round_size(rounded.parse().unwrap(), sz_decimals, mode), rounded);
+    }
+}